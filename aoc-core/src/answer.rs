@@ -0,0 +1,87 @@
+use std::fmt::Display;
+
+/// Answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+}
+
+macro_rules! impl_from_integer {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Self::Integer(i128::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i32, i64, u32, u64);
+
+/// Integers too large for `i128` are kept as their decimal digits instead.
+macro_rules! impl_from_large_integer {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    i128::try_from(value).map_or_else(|_| Self::Text(value.to_string()), Self::Integer)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_large_integer!(u128, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(int) => int.fmt(f),
+            Answer::Text(text) => text.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Answer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Integer(int) => serializer.serialize_i128(*int),
+            Answer::Text(text) => serializer.serialize_str(text),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(-5i32), Answer::Integer(-5));
+        assert_eq!(Answer::from(56000011u128), Answer::Integer(56000011));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+        assert_eq!(Answer::from("CMZ"), Answer::Text("CMZ".to_string()));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Integer(-24933642).to_string(), "-24933642");
+        assert_eq!(Answer::Text("MCD".to_string()).to_string(), "MCD");
+    }
+}
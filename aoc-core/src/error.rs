@@ -0,0 +1,139 @@
+use std::{error::Error, fmt::Display};
+
+/// Error produced when puzzle input does not match the expected format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the offending input, starting at 1.
+    pub line: usize,
+    /// Column of the offending input within its line, starting at 1.
+    pub column: usize,
+    /// Whole line containing the offending input.
+    pub snippet: String,
+    /// Description of what was expected at this position.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error located at the start of `position`, which must be a slice of `input`.
+    pub fn new(input: &str, position: &str, expected: impl Into<String>) -> Self {
+        let offset = (position.as_ptr() as usize).saturating_sub(input.as_ptr() as usize);
+        Self::at_offset(input, offset, expected)
+    }
+
+    /// Creates an error located `offset` bytes into `input`.
+    pub fn at_offset(input: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+
+        Self {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end].trim_end_matches('\r').to_string(),
+            expected: expected.into(),
+        }
+    }
+}
+
+/// Fails unless only whitespace is left in `rest` after parsing `input`.
+///
+/// Parsers for lists stop at the first item they cannot parse, so anything left over
+/// is reported as a malformed item.
+pub fn expect_end(input: &str, rest: &str, expected: &str) -> Result<(), ParseError> {
+    let rest = rest.trim_start();
+    if rest.is_empty() {
+        Ok(())
+    } else {
+        Err(ParseError::new(input, rest, expected))
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "expected {} at line {}, column {}:",
+            self.expected, self.line, self.column
+        )?;
+        writeln!(f, "{}", self.snippet)?;
+        write!(f, "{:>width$}", "^", width = self.column)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Error produced when well-formed puzzle input has no answer, like a maze without a way out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    /// Description of why there is no answer.
+    pub reason: String,
+}
+
+impl SolveError {
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.reason)
+    }
+}
+
+impl Error for SolveError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "2-4,6-8\n2-3,4-x\n5-7,7-9";
+
+    #[test]
+    fn test_new() {
+        let err = ParseError::new(INPUT, &INPUT[14..], "integer");
+
+        assert_eq!(err.line, 2);
+        assert_eq!(err.column, 7);
+        assert_eq!(err.snippet, "2-3,4-x");
+        assert_eq!(err.expected, "integer");
+    }
+
+    #[test]
+    fn test_at_offset() {
+        let err = ParseError::at_offset("ab\r\ncd", 5, "digit");
+
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.snippet, "cd");
+
+        let err = ParseError::at_offset("", 10, "digit");
+
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.snippet, "");
+    }
+
+    #[test]
+    fn test_expect_end() {
+        assert_eq!(expect_end(INPUT, "\n", "pair"), Ok(()));
+
+        let err = expect_end(INPUT, &INPUT[7..], "pair").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new(INPUT, &INPUT[14..], "integer");
+
+        assert_eq!(
+            err.to_string(),
+            "expected integer at line 2, column 7:\n2-3,4-x\n      ^"
+        );
+    }
+}
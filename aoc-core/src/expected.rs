@@ -0,0 +1,109 @@
+use crate::ParseError;
+
+/// Known answer to one part of a puzzle for a named input file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedAnswer {
+    /// Name of the input file without the `.txt` extension, like `input` or `example`.
+    pub input: String,
+    pub part: u8,
+    pub answer: String,
+}
+
+/// Parses an answers file, where every answer is written as `<input> <part>: <answer>`.
+///
+/// Multi-line answers leave the value after the colon empty and follow with
+/// one `| `-prefixed line per line of the answer. Blank lines and lines starting
+/// with `#` are ignored.
+pub fn parse_expected_answers(text: &str) -> Result<Vec<ExpectedAnswer>, ParseError> {
+    let mut answers: Vec<ExpectedAnswer> = Vec::new();
+    let mut continued = false;
+
+    for line in text.lines() {
+        let line = line.trim_end();
+
+        if let Some(rest) = line.strip_prefix('|') {
+            let answer = match answers.last_mut() {
+                Some(expected) if continued => &mut expected.answer,
+                _ => return Err(ParseError::new(text, line, "answer before continuation line")),
+            };
+
+            if !answer.is_empty() {
+                answer.push('\n');
+            }
+            answer.push_str(rest.strip_prefix(' ').unwrap_or(rest));
+            continue;
+        }
+
+        if line.is_empty() || line.starts_with('#') {
+            continued = false;
+            continue;
+        }
+
+        let (key, answer) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::new(text, line, "answer like `input 1: 1234`"))?;
+        let (input, part) = key
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(text, line, "input name and part like `example 2`"))?;
+        let part = part
+            .parse()
+            .map_err(|_| ParseError::new(text, part, "part number"))?;
+
+        let answer = answer.trim();
+        continued = answer.is_empty();
+        answers.push(ExpectedAnswer {
+            input: input.to_string(),
+            part,
+            answer: answer.to_string(),
+        });
+    }
+
+    Ok(answers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expected_answers() {
+        let text = "# Comment
+input 1: 13720
+example 2:
+| ##..
+| .##.
+
+example-larger 2: 36";
+
+        let answers = parse_expected_answers(text).unwrap();
+
+        assert_eq!(
+            answers,
+            vec![
+                ExpectedAnswer {
+                    input: "input".to_string(),
+                    part: 1,
+                    answer: "13720".to_string(),
+                },
+                ExpectedAnswer {
+                    input: "example".to_string(),
+                    part: 2,
+                    answer: "##..\n.##.".to_string(),
+                },
+                ExpectedAnswer {
+                    input: "example-larger".to_string(),
+                    part: 2,
+                    answer: "36".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_expected_answers_error() {
+        assert!(parse_expected_answers("input: 5").is_err());
+        assert!(parse_expected_answers("input one: 5").is_err());
+        assert!(parse_expected_answers("| ##").is_err());
+        assert!(parse_expected_answers("input 1: 5\n| ##").is_err());
+    }
+}
@@ -0,0 +1,49 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::Path,
+};
+
+/// Reads puzzle input from `path`, or from stdin if `path` is `-`, with line endings normalised.
+pub fn read_input(path: impl AsRef<Path>) -> io::Result<String> {
+    let path = path.as_ref();
+
+    let contents = if path == Path::new("-") {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents)?;
+        contents
+    } else {
+        fs::read_to_string(path)?
+    };
+
+    Ok(normalize_line_endings(contents))
+}
+
+/// Reads puzzle input from the path given as the first command line argument,
+/// falling back to `default` when there is none.
+pub fn read_input_from_args(default: impl AsRef<Path>) -> io::Result<String> {
+    match env::args_os().nth(1) {
+        Some(path) => read_input(path),
+        None => read_input(default),
+    }
+}
+
+/// Converts CRLF line endings to LF, so inputs parse the same regardless of the checkout.
+pub fn normalize_line_endings(input: String) -> String {
+    if input.contains('\r') {
+        input.replace("\r\n", "\n")
+    } else {
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_line_endings() {
+        assert_eq!(normalize_line_endings("1\r\n2\r\n\r\n3".to_string()), "1\n2\n\n3");
+        assert_eq!(normalize_line_endings("1\n2\n".to_string()), "1\n2\n");
+    }
+}
@@ -0,0 +1,27 @@
+mod answer;
+mod error;
+mod expected;
+mod input;
+mod log;
+
+pub use answer::Answer;
+pub use error::{expect_end, ParseError, SolveError};
+pub use expected::{parse_expected_answers, ExpectedAnswer};
+pub use input::{normalize_line_endings, read_input, read_input_from_args};
+pub use log::{init_logging, LOG_ENV};
+
+/// Solution to a single day's puzzle, split into parsing and solving phases.
+///
+/// Both parts are solved from the same parsed input, so parsing happens only once
+/// and can be timed on its own. Neither phase panics, whatever the input: malformed input
+/// fails to parse and input without an answer fails to solve.
+pub trait Solution {
+    /// Parsed puzzle input. May borrow from the raw input text.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError>;
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError>;
+}
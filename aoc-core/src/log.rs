@@ -0,0 +1,44 @@
+use std::{env, io};
+
+use tracing_subscriber::EnvFilter;
+
+/// Environment variable holding the log filter, such as `day15=debug`, used when none is given.
+pub const LOG_ENV: &str = "AOC_LOG";
+
+/// Sends log events to stderr, filtered by `filter` or else by `AOC_LOG`.
+///
+/// Filters name a level, or a target and level such as `day15=debug`, with each day logging
+/// to its crate name. Nothing is logged when neither filter is set, and calling this again
+/// once logging is set up does nothing.
+pub fn init_logging(filter: Option<&str>) -> Result<(), String> {
+    let Some(filter) = filter.map(str::to_owned).or_else(|| env::var(LOG_ENV).ok()) else {
+        return Ok(());
+    };
+
+    let filter = parse_filter(&filter)?;
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .without_time()
+        .try_init();
+
+    Ok(())
+}
+
+fn parse_filter(filter: &str) -> Result<EnvFilter, String> {
+    EnvFilter::builder()
+        .parse(filter)
+        .map_err(|err| format!("Invalid log filter `{filter}`: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_filter() {
+        assert!(parse_filter("debug").is_ok());
+        assert!(parse_filter("day15=debug,day17=trace").is_ok());
+        assert!(parse_filter("day15=loud").is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use aoc_core::Solution;
+use rand::{seq::IndexedRandom, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+pub use rand;
+
+/// Seed of generated inputs when none is given.
+pub const DEFAULT_SEED: u64 = 2022;
+
+/// Random number generator of inputs, which gives the same numbers for a seed on every platform.
+pub type InputRng = ChaCha8Rng;
+
+/// Solution that can make up random but valid puzzle inputs, for stress tests and benchmarks.
+pub trait Generated: Solution {
+    /// Generates an input with about `size` of the day's items, like lines, monkeys or valves.
+    fn generate(rng: &mut InputRng, size: usize) -> String;
+}
+
+/// Generates an input of `size` for `G`, always the same one for the same `seed`.
+pub fn generate<G: Generated>(seed: u64, size: usize) -> String {
+    G::generate(&mut InputRng::seed_from_u64(seed), size)
+}
+
+/// Random word of `len` characters picked from `letters`.
+pub fn word(rng: &mut InputRng, letters: &[u8], len: usize) -> String {
+    (0..len)
+        .map(|_| *letters.choose(rng).expect("No letters to pick from") as char)
+        .collect()
+}
+
+pub const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+pub const UPPERCASE: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+#[cfg(test)]
+mod tests {
+    use rand::RngExt;
+
+    use super::*;
+
+    #[test]
+    fn test_seeded() {
+        let mut first = InputRng::seed_from_u64(DEFAULT_SEED);
+        let mut second = InputRng::seed_from_u64(DEFAULT_SEED);
+
+        let numbers: Vec<u32> = (0..8).map(|_| first.random_range(0..1000)).collect();
+        assert_eq!(
+            numbers,
+            (0..8)
+                .map(|_| second.random_range(0..1000))
+                .collect::<Vec<u32>>()
+        );
+    }
+
+    #[test]
+    fn test_word() {
+        let mut rng = InputRng::seed_from_u64(DEFAULT_SEED);
+
+        let word = word(&mut rng, b"ab", 10);
+
+        assert_eq!(word.len(), 10);
+        assert!(word.chars().all(|ch| ch == 'a' || ch == 'b'));
+    }
+}
@@ -0,0 +1,88 @@
+use std::ops::{Add, Sub};
+
+/// Points which can be compared coordinate by coordinate.
+pub trait Componentwise: Copy {
+    /// Takes the smaller value of every coordinate.
+    fn min(self, other: Self) -> Self;
+
+    /// Takes the larger value of every coordinate.
+    fn max(self, other: Self) -> Self;
+}
+
+/// Smallest axis-aligned box containing a set of points, with both corners inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox<P> {
+    pub min: P,
+    pub max: P,
+}
+
+impl<P: Componentwise + PartialEq> BoundingBox<P> {
+    pub fn new(point: P) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// Returns the bounding box of `points`, or `None` if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = P>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        Some(points.fold(Self::new(first), |bounds, point| bounds.include(point)))
+    }
+
+    /// Grows the box so that it also contains `point`.
+    pub fn include(self, point: P) -> Self {
+        Self {
+            min: self.min.min(point),
+            max: self.max.max(point),
+        }
+    }
+
+    pub fn contains(&self, point: P) -> bool {
+        point.min(self.min) == self.min && point.max(self.max) == self.max
+    }
+
+    /// Grows the box by `margin` on every side.
+    pub fn expand(self, margin: P) -> Self
+    where
+        P: Add<Output = P> + Sub<Output = P>,
+    {
+        Self {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point2, Point3};
+
+    use super::*;
+
+    #[test]
+    fn test_from_points() {
+        let bounds = BoundingBox::from_points([
+            Point2::new(3, -1),
+            Point2::new(-2, 4),
+            Point2::new(0, 0),
+        ])
+        .unwrap();
+
+        assert_eq!(bounds.min, Point2::new(-2, -1));
+        assert_eq!(bounds.max, Point2::new(3, 4));
+        assert!(BoundingBox::<Point2<i32>>::from_points([]).is_none());
+    }
+
+    #[test]
+    fn test_contains() {
+        let bounds = BoundingBox::from_points([Point3::new(0, 0, 0), Point3::new(2, 2, 2)])
+            .unwrap()
+            .expand(Point3::new(1, 1, 1));
+
+        assert!(bounds.contains(Point3::new(-1, 3, 0)));
+        assert!(!bounds.contains(Point3::new(-2, 0, 0)));
+    }
+}
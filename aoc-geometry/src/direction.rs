@@ -0,0 +1,58 @@
+use crate::{Number, Point2};
+
+/// One of the four orthogonal directions, with the y axis pointing up. Grids of `aoc-grid` have
+/// rows growing downwards instead, and `aoc_grid::offset` gives the steps between their cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions in clockwise order, starting from `Up`.
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// Offset of a single step in this direction.
+    pub fn delta<T: Number>(self) -> Point2<T> {
+        match self {
+            Direction::Up => Point2::new(T::ZERO, T::ONE),
+            Direction::Right => Point2::new(T::ONE, T::ZERO),
+            Direction::Down => Point2::new(T::ZERO, -T::ONE),
+            Direction::Left => Point2::new(-T::ONE, T::ZERO),
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Right.opposite(), Direction::Left);
+    }
+
+    #[test]
+    fn test_delta() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.delta::<i32>().rotate_right(), dir.turn_right().delta());
+            assert_eq!(dir.delta::<i32>() + dir.opposite().delta(), Point2::new(0, 0));
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod bounds;
+mod direction;
+mod number;
+mod point;
+
+pub use bounds::{BoundingBox, Componentwise};
+pub use direction::Direction;
+pub use number::Number;
+pub use point::{Point2, Point3};
@@ -0,0 +1,39 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Signed integer usable as a point coordinate.
+pub trait Number:
+    Copy
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_number {
+    ($($int:ty),*) => {
+        $(
+            impl Number for $int {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$int>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$int>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(i8, i16, i32, i64, i128, isize);
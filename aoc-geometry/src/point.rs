@@ -0,0 +1,261 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::{Componentwise, Number};
+
+/// Point or vector in 2D space.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Point or vector in 3D space.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Number> Point2<T> {
+    pub const ORIGIN: Self = Self::new(T::ZERO, T::ZERO);
+
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Replaces every coordinate with its sign, giving a step of length 1 in each axis.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Rotates by 90 degrees counterclockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates by 90 degrees clockwise around the origin.
+    pub fn rotate_right(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// The 4 orthogonally adjacent points.
+    pub fn neighbours_4(self) -> [Self; 4] {
+        let (zero, one) = (T::ZERO, T::ONE);
+
+        [
+            self + Self::new(zero, one),
+            self + Self::new(one, zero),
+            self + Self::new(zero, -one),
+            self + Self::new(-one, zero),
+        ]
+    }
+
+    /// The 8 orthogonally or diagonally adjacent points.
+    pub fn neighbours_8(self) -> [Self; 8] {
+        let (zero, one) = (T::ZERO, T::ONE);
+
+        [
+            self + Self::new(-one, one),
+            self + Self::new(zero, one),
+            self + Self::new(one, one),
+            self + Self::new(-one, zero),
+            self + Self::new(one, zero),
+            self + Self::new(-one, -one),
+            self + Self::new(zero, -one),
+            self + Self::new(one, -one),
+        ]
+    }
+}
+
+impl<T: Number> Point3<T> {
+    pub const ORIGIN: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    /// Replaces every coordinate with its sign, giving a step of length 1 in each axis.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// The 6 points sharing a face with this one.
+    pub fn neighbours_6(self) -> [Self; 6] {
+        let (zero, one) = (T::ZERO, T::ONE);
+
+        [
+            self + Self::new(-one, zero, zero),
+            self + Self::new(one, zero, zero),
+            self + Self::new(zero, -one, zero),
+            self + Self::new(zero, one, zero),
+            self + Self::new(zero, zero, -one),
+            self + Self::new(zero, zero, one),
+        ]
+    }
+}
+
+macro_rules! impl_point_ops {
+    ($point:ident { $($field:ident),* }) => {
+        impl<T: Number> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),* }
+            }
+        }
+
+        impl<T: Number> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),* }
+            }
+        }
+
+        impl<T: Number> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                Self { $($field: self.$field * rhs),* }
+            }
+        }
+
+        impl<T: Number> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: Number> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: Number> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<T: Number> Componentwise for $point<T> {
+            fn min(self, other: Self) -> Self {
+                Self { $($field: self.$field.min(other.$field)),* }
+            }
+
+            fn max(self, other: Self) -> Self {
+                Self { $($field: self.$field.max(other.$field)),* }
+            }
+        }
+    };
+}
+
+impl_point_ops!(Point2 { x, y });
+impl_point_ops!(Point3 { x, y, z });
+
+macro_rules! impl_tuple_conversions {
+    ($($int:ty),*) => {
+        $(
+            impl From<($int, $int)> for Point2<$int> {
+                fn from((x, y): ($int, $int)) -> Self {
+                    Self { x, y }
+                }
+            }
+
+            impl From<Point2<$int>> for ($int, $int) {
+                fn from(point: Point2<$int>) -> Self {
+                    (point.x, point.y)
+                }
+            }
+
+            impl From<($int, $int, $int)> for Point3<$int> {
+                fn from((x, y, z): ($int, $int, $int)) -> Self {
+                    Self { x, y, z }
+                }
+            }
+
+            impl From<Point3<$int>> for ($int, $int, $int) {
+                fn from(point: Point3<$int>) -> Self {
+                    (point.x, point.y, point.z)
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple_conversions!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operators() {
+        let mut p = Point2::new(1, 2);
+        p += Point2::new(3, -4);
+
+        assert_eq!(p, Point2::new(4, -2));
+        assert_eq!(p - Point2::new(4, 4), Point2::new(0, -6));
+        assert_eq!(p * 3, Point2::new(12, -6));
+        assert_eq!(-p, Point2::new(-4, 2));
+        assert_eq!(Point3::new(1, 2, 3) + Point3::new(1, 1, 1), Point3::new(2, 3, 4));
+    }
+
+    #[test]
+    fn test_distances() {
+        let p = Point2::new(2, 18);
+        let q = Point2::new(-2, 15);
+
+        assert_eq!(p.manhattan(q), 7);
+        assert_eq!(p.chebyshev(q), 4);
+        assert_eq!(Point3::new(1, 1, 1).manhattan(Point3::ORIGIN), 3);
+        assert_eq!(Point3::new(1, -5, 1).chebyshev(Point3::ORIGIN), 5);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let p = Point2::new(2, 1);
+
+        assert_eq!(p.rotate_left(), Point2::new(-1, 2));
+        assert_eq!(p.rotate_right(), Point2::new(1, -2));
+        assert_eq!(p.rotate_left().rotate_right(), p);
+        assert_eq!(Point2::new(-3, 7).signum(), Point2::new(-1, 1));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = Point2::new(0i64, 0);
+
+        assert!(p.neighbours_4().iter().all(|n| n.manhattan(p) == 1));
+        assert!(p.neighbours_8().iter().all(|n| n.chebyshev(p) == 1));
+        assert!(Point3::new(1, 1, 1).neighbours_6().contains(&Point3::new(1, 1, 0)));
+    }
+
+    #[test]
+    fn test_tuple_conversions() {
+        assert_eq!(Point2::from((1, 2)), Point2::new(1, 2));
+        assert_eq!(<(i64, i64)>::from(Point2::new(3, 4)), (3, 4));
+    }
+}
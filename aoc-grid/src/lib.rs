@@ -0,0 +1,326 @@
+use std::{
+    iter,
+    ops::{Index, IndexMut},
+};
+
+use aoc_core::ParseError;
+use aoc_geometry::Direction;
+
+/// Position of a cell as `(x, y)`, with `y` growing downwards as rows do, unlike the y axis of
+/// [`aoc_geometry::Point2`] which points up. Use [`offset`] to step in a [`Direction`].
+pub type Position = (i64, i64);
+
+/// Offset of a single step in `direction` between cells, so that [`Direction::Up`] goes to the
+/// row above.
+pub fn offset(direction: Direction) -> Position {
+    let delta = direction.delta::<i64>();
+    (delta.x, -delta.y)
+}
+
+const NEIGHBOURS_4: [Position; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+const NEIGHBOURS_8: [Position; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Rectangular grid of cells stored row by row in a flat `Vec`.
+///
+/// The top left cell is at `origin`, which is `(0, 0)` unless moved with [`Grid::with_origin`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    origin: Position,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(width > 0, "Rows of a grid cannot have width 0");
+
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+            origin: (0, 0),
+        }
+    }
+
+    /// Creates a grid from cells given row by row.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't fill rows of width {width}",
+            cells.len()
+        );
+
+        Self {
+            height: cells.len() / width,
+            cells,
+            width,
+            origin: (0, 0),
+        }
+    }
+
+    /// Parses a character map, one row per line, converting every character with `cell`.
+    ///
+    /// Characters for which `cell` returns `None` are reported as not being `expected`.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let width = input
+            .lines()
+            .next()
+            .filter(|line| !line.is_empty())
+            .ok_or_else(|| ParseError::new(input, input, "row of cells"))?
+            .chars()
+            .count();
+
+        let mut cells = Vec::with_capacity(width * input.lines().count());
+        for line in input.lines() {
+            if line.chars().count() != width {
+                return Err(ParseError::new(input, line, format!("row of {width} cells")));
+            }
+
+            for (i, ch) in line.char_indices() {
+                let value = cell(ch).ok_or_else(|| ParseError::new(input, &line[i..], expected))?;
+                cells.push(value);
+            }
+        }
+
+        Ok(Self::from_vec(width, cells))
+    }
+
+    /// Moves the top left cell to `origin`, shifting all positions accordingly.
+    pub fn with_origin(mut self, origin: Position) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn origin(&self) -> Position {
+        self.origin
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.index_of(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.index_of(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.cells.len()).map(|idx| self.position_of(idx))
+    }
+
+    /// Iterates over all cells along with their positions, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Returns the position of the first cell, row by row, matching `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.cells.iter().position(predicate).map(|idx| self.position_of(idx))
+    }
+
+    /// Positions of the up to 4 orthogonally adjacent cells inside the grid.
+    pub fn neighbours_4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(pos, &NEIGHBOURS_4)
+    }
+
+    /// Positions of the up to 8 orthogonally or diagonally adjacent cells inside the grid.
+    pub fn neighbours_8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(pos, &NEIGHBOURS_8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        (x, y): Position,
+        offsets: &'static [Position],
+    ) -> impl Iterator<Item = Position> + 'a {
+        offsets
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&pos| self.contains(pos))
+    }
+
+    /// Iterates over the cells of row `y` from left to right.
+    pub fn row(&self, y: i64) -> impl Iterator<Item = &T> {
+        let start = self.index_of((self.origin.0, y)).expect("Row outside of the grid");
+        self.cells[start..start + self.width].iter()
+    }
+
+    /// Iterates over the cells of column `x` from top to bottom.
+    pub fn column(&self, x: i64) -> impl Iterator<Item = &T> {
+        let start = self.index_of((x, self.origin.1)).expect("Column outside of the grid");
+        self.cells[start..].iter().step_by(self.width)
+    }
+
+    /// Iterates over the cells reached by repeatedly stepping by `step` from `from`,
+    /// excluding `from` itself, until leaving the grid.
+    pub fn ray(&self, from: Position, (dx, dy): Position) -> impl Iterator<Item = &T> {
+        iter::successors(Some(from), move |&(x, y)| Some((x + dx, y + dy)))
+            .skip(1)
+            .map_while(|pos| self.get(pos))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+            origin: self.origin,
+        }
+    }
+
+    /// Renders the grid as text, one line per row, drawing every cell with `f`.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for row in self.cells.chunks(self.width) {
+            out.extend(row.iter().map(&mut f));
+            out.push('\n');
+        }
+
+        out
+    }
+
+    fn index_of(&self, (x, y): Position) -> Option<usize> {
+        let x = usize::try_from(x - self.origin.0).ok()?;
+        let y = usize::try_from(y - self.origin.1).ok()?;
+
+        (x < self.width && y < self.height).then_some(x + y * self.width)
+    }
+
+    fn position_of(&self, idx: usize) -> Position {
+        let x = (idx % self.width) as i64;
+        let y = (idx / self.width) as i64;
+
+        (x + self.origin.0, y + self.origin.1)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {pos:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {pos:?} is outside of the grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits() -> Grid<u32> {
+        Grid::parse("123\n456\n789", "digit", |ch| ch.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits();
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[(0, 0)], 1);
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, -1)), None);
+
+        let err = Grid::parse("12\n3x", "digit", |ch| ch.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.expected, "digit");
+
+        let err = Grid::parse("12\n345", "digit", |ch| ch.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert!(Grid::parse("", "digit", |ch| ch.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits();
+
+        assert_eq!(grid.neighbours_4((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours_4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours_8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_offset() {
+        let (dx, dy) = offset(Direction::Up);
+
+        assert_eq!(digits().get((dx, 1 + dy)), Some(&1));
+        assert_eq!(Direction::ALL.map(offset), NEIGHBOURS_4);
+    }
+
+    #[test]
+    fn test_row_column_ray() {
+        let grid = digits();
+
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6, 9]);
+        assert_eq!(grid.ray((1, 1), (0, -1)).copied().collect::<Vec<_>>(), vec![2]);
+        assert_eq!(grid.ray((0, 0), (1, 1)).copied().collect::<Vec<_>>(), vec![5, 9]);
+        assert_eq!(grid.ray((2, 2), (1, 0)).count(), 0);
+    }
+
+    #[test]
+    fn test_origin() {
+        let mut grid = Grid::new(3, 2, '.').with_origin((498, 0));
+        grid[(500, 1)] = '#';
+
+        assert!(grid.contains((498, 0)));
+        assert!(!grid.contains((497, 0)));
+        assert_eq!(grid.position(|&ch| ch == '#'), Some((500, 1)));
+        assert_eq!(grid.column(500).collect::<String>(), ".#");
+        assert_eq!(grid.positions().next(), Some((498, 0)));
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = digits().map(|&d| d % 2 == 0);
+
+        assert_eq!(grid.render(|&even| if even { '#' } else { '.' }), ".#.\n#.#\n.#.\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "width 0")]
+    fn test_new_zero_width() {
+        Grid::new(0, 3, '.');
+    }
+}
@@ -0,0 +1,193 @@
+use std::str::FromStr;
+
+use aoc_core::{expect_end, ParseError};
+use aoc_grid::Grid;
+use nom::{
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{char, digit1, line_ending, satisfy, space0, space1},
+    combinator::{map, map_opt, map_res, opt, recognize, verify},
+    error::Error,
+    multi::{many1, separated_list0, separated_list1},
+    sequence::{pair, preceded, separated_pair, tuple},
+    Finish, IResult, Parser,
+};
+
+/// Runs `parser` on all of `input`, turning a failure or unparsed input other than trailing
+/// whitespace into a [`ParseError`] that reports `expected`.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, O, Error<&'a str>>,
+    expected: &str,
+) -> Result<O, ParseError> {
+    let (rest, output) = parser
+        .parse(input)
+        .finish()
+        .map_err(|err| ParseError::new(input, err.input, expected))?;
+
+    expect_end(input, rest, expected)?;
+
+    Ok(output)
+}
+
+/// Integer without a sign, like `1234`.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// Integer with an optional minus sign, like `-12`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+}
+
+/// Two values separated by `separator`, like the `498,4` coordinates of rock paths.
+pub fn pair_of<'a, O, F>(
+    item: F,
+    separator: &'a str,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (O, O)>
+where
+    F: Parser<&'a str, O, Error<&'a str>> + Clone,
+{
+    separated_pair(item.clone(), tag(separator), item)
+}
+
+/// Three values separated by `separator`, like the `2,2,5` cube positions.
+pub fn triple_of<'a, O, F>(
+    item: F,
+    separator: &'a str,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (O, O, O)>
+where
+    F: Parser<&'a str, O, Error<&'a str>> + Clone,
+{
+    tuple((
+        item.clone(),
+        preceded(tag(separator), item.clone()),
+        preceded(tag(separator), item),
+    ))
+}
+
+/// Value following a fixed `label`, like `x=5`.
+pub fn labelled<'a, O, F>(label: &'a str, value: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    preceded(tag(label), value)
+}
+
+/// Alphanumeric name of exactly `len` characters, like the valve `AA` or the monkey `root`.
+pub fn identifier<'a>(len: usize) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    take_while_m_n(len, len, |c: char| c.is_ascii_alphanumeric())
+}
+
+/// One or more items separated by commas, optionally followed by spaces.
+pub fn comma_separated<'a, O, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(pair(char(','), space0), item)
+}
+
+/// One or more items separated by spaces.
+pub fn space_separated<'a, O, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(space1, item)
+}
+
+/// Items on consecutive lines.
+pub fn lines<'a, O, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list0(line_ending, item)
+}
+
+/// Blocks of lines separated by blank lines.
+pub fn blocks<'a, O, F>(block: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list0(pair(line_ending, line_ending), block)
+}
+
+/// Rectangular grid with one cell per character, converted by `cell`.
+pub fn grid<'a, T, F>(cell: F) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>>
+where
+    F: Fn(char) -> Option<T> + Copy,
+{
+    let row = many1(map_opt(satisfy(|c| c != '\n' && c != '\r'), cell));
+
+    map(
+        verify(separated_list1(line_ending, row), |rows: &Vec<Vec<T>>| {
+            rows.iter().all(|row| row.len() == rows[0].len())
+        }),
+        |rows| {
+            let width = rows[0].len();
+            Grid::from_vec(width, rows.into_iter().flatten().collect())
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(unsigned::<u32>("123,4"), Ok((",4", 123)));
+        assert!(unsigned::<u32>("-123").is_err());
+        assert!(unsigned::<u8>("256").is_err());
+
+        assert_eq!(signed::<i64>("-123"), Ok(("", -123)));
+        assert_eq!(signed::<i64>("45"), Ok(("", 45)));
+    }
+
+    #[test]
+    fn test_coordinates() {
+        assert_eq!(pair_of(signed::<i32>, ",")("498,-4 ->"), Ok((" ->", (498, -4))));
+        assert_eq!(triple_of(unsigned::<u8>, ",")("1,2,3"), Ok(("", (1, 2, 3))));
+
+        let mut point = separated_pair(
+            labelled("x=", signed::<i32>),
+            tag(", "),
+            labelled("y=", signed::<i32>),
+        );
+        assert_eq!(point("x=-2, y=15"), Ok(("", (-2, 15))));
+    }
+
+    #[test]
+    fn test_lists() {
+        assert_eq!(
+            comma_separated(identifier(2))("DD, II,BB"),
+            Ok(("", vec!["DD", "II", "BB"]))
+        );
+        assert_eq!(
+            space_separated(unsigned::<u32>)("1 2  3"),
+            Ok(("", vec![1, 2, 3]))
+        );
+        assert_eq!(
+            blocks(lines(unsigned::<u32>))("1\n2\n\n3\n"),
+            Ok(("\n", vec![vec![1, 2], vec![3]]))
+        );
+        assert!(identifier(4)("ab").is_err());
+    }
+
+    #[test]
+    fn test_grid() {
+        let (rest, grid) = grid(|c| c.to_digit(10))("123\n456\n\nrest").unwrap();
+
+        assert_eq!(rest, "\n\nrest");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+
+        assert!(super::grid(|c| c.to_digit(10))("12\n345").is_err());
+    }
+
+    #[test]
+    fn test_parse_all() {
+        assert_eq!(parse_all("1\n2\n", lines(unsigned::<u32>), "number"), Ok(vec![1, 2]));
+
+        let err = parse_all("1\n2x\n", lines(unsigned::<u32>), "number").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+}
@@ -0,0 +1,153 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::{Path, Paths};
+
+/// Finds the path with the fewest steps from `start` to any node satisfying `is_goal`.
+pub fn bfs<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_multi([start], neighbours, is_goal)
+}
+
+/// Like [`bfs`], but starting from all of `starts` at once, so the path found
+/// begins at whichever start is closest to a goal.
+pub fn bfs_multi<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let (paths, goal) = explore(starts, neighbours, is_goal);
+    paths.path_to(&goal?)
+}
+
+/// Finds the fewest steps from the nearest of `starts` to every reachable node.
+pub fn bfs_all<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    explore(starts, neighbours, |_| false).0
+}
+
+/// Returns all nodes reachable from `start`, including `start` itself.
+pub fn flood_fill<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut stack = vec![start.clone()];
+    let mut visited = HashSet::from([start]);
+    while let Some(node) = stack.pop() {
+        for next in neighbours(&node) {
+            if visited.insert(next.clone()) {
+                stack.push(next);
+            }
+        }
+    }
+
+    visited
+}
+
+/// Runs a breadth-first search until a goal is reached or there is nothing left to visit.
+fn explore<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (Paths<N, usize>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !paths.contains(&start) {
+            paths.insert(start.clone(), 0, None);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, distance)) = queue.pop_front() {
+        if is_goal(&node) {
+            return (paths, Some(node));
+        }
+
+        for next in neighbours(&node) {
+            if !paths.contains(&next) {
+                paths.insert(next.clone(), distance + 1, Some(node.clone()));
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    (paths, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Numbers from 0 to 20, where you can go up by 1 or 3, or down by 2.
+    fn jumps(&n: &i32) -> Vec<i32> {
+        [n + 1, n + 3, n - 2]
+            .into_iter()
+            .filter(|n| (0..=20).contains(n))
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs(0, jumps, |&n| n == 7).unwrap();
+
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes.len(), 4);
+        assert_eq!(path.nodes.first(), Some(&0));
+        assert_eq!(path.nodes.last(), Some(&7));
+
+        assert!(bfs(0, jumps, |&n| n == 21).is_none());
+    }
+
+    #[test]
+    fn test_bfs_multi() {
+        let path = bfs_multi([0, 10], jumps, |&n| n == 14).unwrap();
+
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.nodes.first(), Some(&10));
+        assert_eq!(path.nodes.last(), Some(&14));
+    }
+
+    #[test]
+    fn test_bfs_all() {
+        let paths = bfs_all([0], jumps);
+
+        assert_eq!(paths.distances().len(), 21);
+        assert_eq!(paths.distance(&0), Some(0));
+        assert_eq!(paths.distance(&6), Some(2));
+        assert_eq!(paths.path_to(&6).unwrap().nodes, vec![0, 3, 6]);
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let reached = flood_fill(5, |&n: &i32| {
+            [n - 2, n + 2].into_iter().filter(|n| (0..10).contains(n))
+        });
+
+        assert_eq!(reached, HashSet::from([1, 3, 5, 7, 9]));
+    }
+}
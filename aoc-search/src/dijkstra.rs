@@ -0,0 +1,202 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+use crate::{Cost, Path, Paths};
+
+/// Finds the cheapest path from `start` to any node satisfying `is_goal`,
+/// where `neighbours` yields every adjacent node along with the cost of moving there.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::ZERO, is_goal)
+}
+
+/// Finds the cheapest path from `start` to every reachable node.
+pub fn dijkstra_all<N, C, I>(start: N, neighbours: impl FnMut(&N) -> I) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    explore(start, neighbours, |_| C::ZERO, |_| false).0
+}
+
+/// Like [`dijkstra`], but guided towards the goal by `heuristic`.
+///
+/// The heuristic must never overestimate the remaining cost, otherwise the path found
+/// may not be the cheapest one.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (paths, goal) = explore(start, neighbours, heuristic, is_goal);
+    paths.path_to(&goal?)
+}
+
+/// Finds the cheapest cost between every pair of `nodes`, keyed by `(from, to)`.
+///
+/// Pairs with no path between them are left out.
+pub fn all_pairs<N, C, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> HashMap<(N, N), C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs = HashMap::new();
+    for start in nodes {
+        let paths = dijkstra_all(start.clone(), &mut neighbours);
+        for (node, &cost) in paths.distances() {
+            costs.insert((start.clone(), node.clone()), cost);
+        }
+    }
+
+    costs
+}
+
+/// Node waiting in the queue, ordered so that the lowest estimate is popped first.
+struct Entry<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+fn explore<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (Paths<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    paths.insert(start.clone(), C::ZERO, None);
+
+    let mut queue = BinaryHeap::from([Entry {
+        estimate: heuristic(&start),
+        cost: C::ZERO,
+        node: start,
+    }]);
+    while let Some(Entry { cost, node, .. }) = queue.pop() {
+        if paths.distance(&node).is_some_and(|best| best < cost) {
+            continue;
+        }
+
+        if is_goal(&node) {
+            return (paths, Some(node));
+        }
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if paths.distance(&next).is_none_or(|best| next_cost < best) {
+                paths.insert(next.clone(), next_cost, Some(node.clone()));
+                queue.push(Entry {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    (paths, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Small weighted graph where the direct edge from `a` to `d` is not the cheapest way.
+    fn roads(&node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 4), ('d', 10)],
+            'b' => vec![('c', 2), ('a', 1)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra('a', roads, |&n| n == 'd').unwrap();
+
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.nodes, vec!['a', 'b', 'c', 'd']);
+        assert!(dijkstra('d', roads, |&n| n == 'a').is_none());
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        let paths = dijkstra_all('a', roads);
+
+        assert_eq!(paths.distance(&'c'), Some(3));
+        assert_eq!(paths.path_to(&'c').unwrap().nodes, vec!['a', 'b', 'c']);
+    }
+
+    #[test]
+    fn test_astar() {
+        let goal = (5i32, 3i32);
+        let path = astar(
+            (0, 0),
+            |&(x, y)| [((x + 1, y), 1), ((x, y + 1), 1), ((x - 1, y), 1)],
+            |&(x, y)| (goal.0 - x).abs() + (goal.1 - y).abs(),
+            |&pos| pos == goal,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 8);
+        assert_eq!(path.nodes.len(), 9);
+    }
+
+    #[test]
+    fn test_all_pairs() {
+        let costs = all_pairs(['a', 'b', 'c', 'd'], roads);
+
+        assert_eq!(costs[&('a', 'd')], 6);
+        assert_eq!(costs[&('b', 'a')], 1);
+        assert_eq!(costs[&('d', 'd')], 0);
+        assert!(!costs.contains_key(&('d', 'a')));
+    }
+}
@@ -0,0 +1,7 @@
+mod bfs;
+mod dijkstra;
+mod paths;
+
+pub use bfs::{bfs, bfs_all, bfs_multi, flood_fill};
+pub use dijkstra::{all_pairs, astar, dijkstra, dijkstra_all};
+pub use paths::{Cost, Path, Paths};
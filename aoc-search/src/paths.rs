@@ -0,0 +1,74 @@
+use std::{collections::HashMap, hash::Hash, ops::Add};
+
+/// Cost of moving along an edge, summed up into the cost of a path.
+pub trait Cost: Copy + Ord + Add<Output = Self> {
+    const ZERO: Self;
+}
+
+macro_rules! impl_cost {
+    ($($int:ty),*) => {
+        $(
+            impl Cost for $int {
+                const ZERO: Self = 0;
+            }
+        )*
+    };
+}
+
+impl_cost!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Single path found by a search, from a start node to a goal node inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+/// Shortest distances from the start nodes to every reached node,
+/// along with enough information to reconstruct the paths.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    distances: HashMap<N, C>,
+    parents: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> Paths<N, C> {
+    pub(crate) fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            parents: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn insert(&mut self, node: N, distance: C, parent: Option<N>) {
+        if let Some(parent) = parent {
+            self.parents.insert(node.clone(), parent);
+        }
+        self.distances.insert(node, distance);
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    /// Reconstructs the shortest path from one of the start nodes to `node`.
+    pub fn path_to(&self, node: &N) -> Option<Path<N, C>> {
+        let cost = self.distance(node)?;
+
+        let mut nodes = vec![node.clone()];
+        while let Some(parent) = self.parents.get(nodes.last().unwrap()) {
+            nodes.push(parent.clone());
+        }
+        nodes.reverse();
+
+        Some(Path { nodes, cost })
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+use aoc_core::{Answer, Solution, SolveError};
+
+/// Receiver of the frames of a simulation.
+///
+/// Frames are passed as closures, so simulations that are not being watched never render them.
+pub trait Frames {
+    fn frame(&mut self, render: impl FnOnce() -> String);
+}
+
+/// Discards all frames, for solving without watching.
+pub struct NoFrames;
+
+impl Frames for NoFrames {
+    fn frame(&mut self, _render: impl FnOnce() -> String) {}
+}
+
+/// Solution whose simulation can be watched frame by frame.
+pub trait Animated: Solution {
+    /// Solves `part` like [`Solution::part_1`] or [`Solution::part_2`], pushing frames into `frames`.
+    fn animate(
+        input: &Self::Input<'_>,
+        part: u8,
+        frames: &mut impl Frames,
+    ) -> Result<Answer, SolveError>;
+}
+
+/// Plays frames back in a terminal, or writes them out one after another in headless mode.
+pub struct Animation<W: Write> {
+    out: W,
+    headless: bool,
+    delay: Duration,
+    every: usize,
+    offered: usize,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> Animation<W> {
+    /// Redraws each frame over the previous one, waiting between frames.
+    pub fn terminal(out: W) -> Self {
+        Self::new(out, false)
+    }
+
+    /// Writes every frame below the previous one under a `Frame N` header, without waiting.
+    pub fn headless(out: W) -> Self {
+        Self::new(out, true)
+    }
+
+    fn new(out: W, headless: bool) -> Self {
+        Self {
+            out,
+            headless,
+            delay: Duration::from_millis(50),
+            every: 1,
+            offered: 0,
+            error: None,
+        }
+    }
+
+    /// Plays back `fps` frames per second.
+    pub fn fps(mut self, fps: f64) -> Self {
+        self.delay = Duration::from_secs_f64(1.0 / fps.max(f64::EPSILON));
+        self
+    }
+
+    /// Shows only every `every`-th frame, skipping the ones in between.
+    pub fn every(mut self, every: usize) -> Self {
+        self.every = every.max(1);
+        self
+    }
+
+    /// Number of frames offered so far, including skipped ones.
+    pub fn offered(&self) -> usize {
+        self.offered
+    }
+
+    /// Flushes the output, returning it or the first error that happened while writing frames.
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        self.out.flush()?;
+        Ok(self.out)
+    }
+
+    fn draw(&mut self, index: usize, frame: &str) -> io::Result<()> {
+        if self.headless {
+            writeln!(self.out, "Frame {index}")?;
+            writeln!(self.out, "{}\n", frame.trim_end())?;
+        } else {
+            write!(self.out, "\x1b[H\x1b[2J{frame}")?;
+            self.out.flush()?;
+            thread::sleep(self.delay);
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Frames for Animation<W> {
+    fn frame(&mut self, render: impl FnOnce() -> String) {
+        let index = self.offered;
+        self.offered += 1;
+
+        if self.error.is_some() || !index.is_multiple_of(self.every) {
+            return;
+        }
+
+        if let Err(err) = self.draw(index, &render()) {
+            self.error = Some(err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_headless() {
+        let mut animation = Animation::headless(Vec::new()).every(2);
+        for i in 0..5 {
+            animation.frame(|| format!("#{i}\n"));
+        }
+
+        assert_eq!(animation.offered(), 5);
+
+        let out = String::from_utf8(animation.finish().unwrap()).unwrap();
+        assert_eq!(out, "Frame 0\n#0\n\nFrame 2\n#2\n\nFrame 4\n#4\n\n");
+    }
+
+    #[test]
+    fn test_no_frames() {
+        NoFrames.frame(|| unreachable!("Frames are not rendered"));
+    }
+}
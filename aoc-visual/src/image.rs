@@ -0,0 +1,342 @@
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+use aoc_core::Solution;
+use aoc_grid::Grid;
+
+use crate::Frames;
+
+pub type Rgb = [u8; 3];
+
+/// Solution with a picture of its puzzle, like the final state of a simulation.
+pub trait Pictured: Solution {
+    fn image(input: &Self::Input<'_>) -> Image;
+}
+
+/// Image made of RGB pixels, written without any display or GPU.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, fill: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![fill; width * height],
+        }
+    }
+
+    /// Draws each cell of `grid` as a `scale` by `scale` square coloured by `palette`.
+    pub fn from_grid<T>(grid: &Grid<T>, scale: usize, mut palette: impl FnMut(&T) -> Rgb) -> Self {
+        let mut image = Self::new(grid.width() * scale, grid.height() * scale, [0; 3]);
+        for (i, cell) in grid.iter().enumerate() {
+            image.fill_square(i % grid.width(), i / grid.width(), scale, palette(cell));
+        }
+
+        image
+    }
+
+    /// Draws each character of `text` as a `scale` by `scale` square coloured by `palette`,
+    /// padding short lines with spaces.
+    pub fn from_text(text: &str, scale: usize, mut palette: impl FnMut(char) -> Rgb) -> Self {
+        let width = text
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let height = text.lines().count();
+
+        let mut image = Self::new(width * scale, height * scale, palette(' '));
+        for (y, line) in text.lines().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                image.fill_square(x, y, scale, palette(ch));
+            }
+        }
+
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, colour: Rgb) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = colour;
+        }
+    }
+
+    fn fill_square(&mut self, cell_x: usize, cell_y: usize, scale: usize, colour: Rgb) {
+        for y in cell_y * scale..(cell_y + 1) * scale {
+            for x in cell_x * scale..(cell_x + 1) * scale {
+                self.set(x, y, colour);
+            }
+        }
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flatten().copied().collect()
+    }
+
+    /// Writes the image in the binary PPM (`P6`) format.
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.bytes())
+    }
+
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        write_png(std::slice::from_ref(self), Duration::ZERO, out)
+    }
+}
+
+/// Writes `frames` as consecutive PPM images, which netpbm tools read as a sequence.
+pub fn write_ppm_frames(frames: &[Image], mut out: impl Write) -> io::Result<()> {
+    for frame in frames {
+        frame.write_ppm(&mut out)?;
+    }
+
+    Ok(())
+}
+
+/// Writes `frames` as a PNG, animated with `delay` between frames if there is more than one.
+///
+/// All frames must have the size of the first one.
+pub fn write_png(frames: &[Image], delay: Duration, out: impl Write) -> io::Result<()> {
+    let first = frames
+        .first()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "No frames to write"))?;
+    if let Some(frame) = frames
+        .iter()
+        .find(|frame| (frame.width, frame.height) != (first.width, first.height))
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "Frame of {}x{} pixels in an animation of {}x{} pixels",
+                frame.width, frame.height, first.width, first.height
+            ),
+        ));
+    }
+
+    let mut encoder = png::Encoder::new(out, first.width as u32, first.height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    if frames.len() > 1 {
+        encoder
+            .set_animated(frames.len() as u32, 0)
+            .map_err(io::Error::other)?;
+        let millis = delay.as_millis().min(u16::MAX as u128) as u16;
+        encoder
+            .set_frame_delay(millis, 1000)
+            .map_err(io::Error::other)?;
+    }
+
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    for frame in frames {
+        writer
+            .write_image_data(&frame.bytes())
+            .map_err(io::Error::other)?;
+    }
+
+    writer.finish().map_err(io::Error::other)
+}
+
+/// Colours for the characters days draw their frames with.
+pub fn default_palette(ch: char) -> Rgb {
+    match ch {
+        '.' | ' ' => [15, 15, 35],
+        '#' => [204, 204, 204],
+        'o' => [230, 180, 80],
+        'H' => [220, 60, 60],
+        '0'..='9' => {
+            let shade = (ch as u8 - b'0') * 20;
+            [60 + shade, 140 + shade / 2, 60]
+        }
+        _ => [120, 120, 120],
+    }
+}
+
+/// Collects text frames as images, to save a simulation as a picture sequence.
+///
+/// Every frame is kept in memory at full scale until it is written, so there is a cap on how many
+/// frames are collected.
+pub struct ImageFrames {
+    scale: usize,
+    palette: fn(char) -> Rgb,
+    every: usize,
+    max_frames: usize,
+    offered: usize,
+    frames: Vec<Image>,
+    exceeded: bool,
+}
+
+impl ImageFrames {
+    /// Collects at most `max_frames` frames, failing in [`into_frames`](Self::into_frames) if
+    /// there are more.
+    pub fn new(scale: usize, palette: fn(char) -> Rgb, max_frames: usize) -> Self {
+        Self {
+            scale,
+            palette,
+            every: 1,
+            max_frames,
+            offered: 0,
+            frames: Vec::new(),
+            exceeded: false,
+        }
+    }
+
+    /// Keeps only every `every`-th frame, skipping the ones in between.
+    pub fn every(mut self, every: usize) -> Self {
+        self.every = every.max(1);
+        self
+    }
+
+    /// Collected images, padded to the size of the largest frame, or an error if there were more
+    /// frames than the cap.
+    pub fn into_frames(self) -> io::Result<Vec<Image>> {
+        if self.exceeded {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Animation has more than {} frames", self.max_frames),
+            ));
+        }
+
+        let width = self.frames.iter().map(Image::width).max().unwrap_or(0);
+        let height = self.frames.iter().map(Image::height).max().unwrap_or(0);
+        let background = (self.palette)(' ');
+
+        Ok(self
+            .frames
+            .into_iter()
+            .map(|frame| {
+                if (frame.width, frame.height) == (width, height) {
+                    return frame;
+                }
+
+                let mut padded = Image::new(width, height, background);
+                for y in 0..frame.height {
+                    for x in 0..frame.width {
+                        padded.set(x, y, frame.pixels[y * frame.width + x]);
+                    }
+                }
+                padded
+            })
+            .collect())
+    }
+}
+
+impl Frames for ImageFrames {
+    fn frame(&mut self, render: impl FnOnce() -> String) {
+        let index = self.offered;
+        self.offered += 1;
+
+        if self.exceeded || !index.is_multiple_of(self.every) {
+            return;
+        }
+
+        // Frames past the cap are not rendered, as collecting them is bound to fail anyway.
+        if self.frames.len() == self.max_frames {
+            self.exceeded = true;
+            self.frames = Vec::new();
+            return;
+        }
+
+        self.frames
+            .push(Image::from_text(&render(), self.scale, self.palette));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgb = [255, 0, 0];
+    const BLUE: Rgb = [0, 0, 255];
+
+    #[test]
+    fn test_from_grid() {
+        let grid = Grid::from_vec(2, vec![true, false, false, true]);
+
+        let image = Image::from_grid(&grid, 2, |&red| if red { RED } else { BLUE });
+
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(image.get(1, 1), Some(RED));
+        assert_eq!(image.get(2, 1), Some(BLUE));
+        assert_eq!(image.get(3, 3), Some(RED));
+        assert_eq!(image.get(4, 0), None);
+    }
+
+    #[test]
+    fn test_write_ppm() {
+        let image = Image::from_text("#.\n#", 1, |ch| if ch == '#' { RED } else { BLUE });
+        let mut ppm = Vec::new();
+
+        image.write_ppm(&mut ppm).unwrap();
+
+        let mut expected = b"P6\n2 2\n255\n".to_vec();
+        expected.extend([RED, BLUE, RED, BLUE].concat());
+        assert_eq!(ppm, expected);
+    }
+
+    #[test]
+    fn test_write_png() {
+        let frames = vec![Image::new(3, 2, RED), Image::new(3, 2, BLUE)];
+        let mut png = Vec::new();
+
+        write_png(&frames, Duration::from_millis(100), &mut png).unwrap();
+
+        let mut reader = png::Decoder::new(io::Cursor::new(png)).read_info().unwrap();
+        assert_eq!(reader.info().animation_control.unwrap().num_frames, 2);
+
+        let mut buf = vec![0; reader.output_buffer_size().unwrap()];
+        reader.next_frame(&mut buf).unwrap();
+        assert_eq!(&buf[..3], &RED);
+        reader.next_frame(&mut buf).unwrap();
+        assert_eq!(&buf[..3], &BLUE);
+
+        assert!(write_png(
+            &[Image::new(1, 1, RED), Image::new(2, 1, RED)],
+            Duration::ZERO,
+            &mut Vec::new()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_image_frames() {
+        let mut frames = ImageFrames::new(1, default_palette, 2).every(2);
+        for i in 1..=3 {
+            frames.frame(|| "#".repeat(i));
+        }
+
+        let frames = frames.into_frames().unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!((frames[0].width(), frames[1].width()), (3, 3));
+        assert_eq!(frames[0].get(1, 0), Some(default_palette(' ')));
+        assert_eq!(frames[1].get(2, 0), Some(default_palette('#')));
+    }
+
+    #[test]
+    fn test_image_frames_cap() {
+        let mut frames = ImageFrames::new(1, default_palette, 2);
+        for _ in 0..3 {
+            frames.frame(|| "#".to_string());
+        }
+
+        assert!(frames.into_frames().is_err());
+    }
+}
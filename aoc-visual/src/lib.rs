@@ -0,0 +1,5 @@
+mod animation;
+mod image;
+
+pub use animation::{Animated, Animation, Frames, NoFrames};
+pub use image::{default_palette, write_png, write_ppm_frames, Image, ImageFrames, Pictured, Rgb};
@@ -0,0 +1,192 @@
+use std::{collections::HashMap, fmt::Display, time::Duration};
+
+use aoc_core::{ParseError, SolveError};
+
+/// Timings of every phase of a day, collected over repeated runs.
+#[derive(Debug, Default)]
+pub struct Samples {
+    pub parse: Vec<Duration>,
+    pub parts: Vec<(u8, Vec<Duration>)>,
+}
+
+impl Samples {
+    /// Named phases with their timings, in the order they were run.
+    pub fn phases(&self) -> impl Iterator<Item = (String, &[Duration])> {
+        let parse = ("parse".to_string(), self.parse.as_slice());
+        let parts = self
+            .parts
+            .iter()
+            .map(|(part, samples)| (format!("part{part}"), samples.as_slice()));
+
+        std::iter::once(parse).chain(parts)
+    }
+}
+
+/// Why a day could not be benchmarked.
+#[derive(Debug)]
+pub enum BenchError {
+    Parse(ParseError),
+    /// A part with no answer, whose timings would not mean much.
+    Solve(u8, SolveError),
+}
+
+impl From<ParseError> for BenchError {
+    fn from(err: ParseError) -> Self {
+        BenchError::Parse(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "No samples to compute statistics from");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Self {
+            mean,
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "mean {:>10.3?}  median {:>10.3?}  stddev {:>10.3?}",
+            self.mean, self.median, self.stddev
+        )
+    }
+}
+
+/// Mean timings of earlier runs, keyed by day and phase name.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    means: HashMap<(u8, String), Duration>,
+}
+
+impl Baseline {
+    /// Parses a baseline with one `<day> <phase> <mean in nanoseconds>` entry per line.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut means = HashMap::new();
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            let invalid = || format!("Invalid baseline entry `{line}`");
+
+            let mut fields = line.split_whitespace();
+            let (Some(day), Some(phase), Some(nanos), None) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(invalid());
+            };
+
+            let day = day.parse().map_err(|_| invalid())?;
+            let nanos = nanos.parse().map_err(|_| invalid())?;
+            means.insert((day, phase.to_string()), Duration::from_nanos(nanos));
+        }
+
+        Ok(Self { means })
+    }
+
+    pub fn insert(&mut self, day: u8, phase: &str, mean: Duration) {
+        self.means.insert((day, phase.to_string()), mean);
+    }
+
+    pub fn get(&self, day: u8, phase: &str) -> Option<Duration> {
+        self.means.get(&(day, phase.to_string())).copied()
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut entries: Vec<_> = self.means.iter().collect();
+        entries.sort();
+
+        for ((day, phase), mean) in entries {
+            writeln!(f, "{day} {phase} {}", mean.as_nanos())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Relative change of `mean` compared to `baseline`, in percent, or `None` if the baseline is
+/// zero and there is nothing to compare with.
+pub fn change_percent(baseline: Duration, mean: Duration) -> Option<f64> {
+    if baseline.is_zero() {
+        return None;
+    }
+
+    Some((mean.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(&millis(&[4, 2, 6, 4]));
+
+        assert_eq!(stats.mean, Duration::from_millis(4));
+        assert_eq!(stats.median, Duration::from_millis(4));
+        assert_eq!(stats.stddev.as_micros(), 1414);
+
+        let stats = Stats::new(&millis(&[10, 1, 3]));
+
+        assert_eq!(stats.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_baseline() {
+        let mut baseline = Baseline::default();
+        baseline.insert(12, "parse", Duration::from_micros(150));
+        baseline.insert(3, "part2", Duration::from_millis(2));
+
+        let text = baseline.to_string();
+        assert_eq!(text, "3 part2 2000000\n12 parse 150000\n");
+        assert_eq!(Baseline::parse(&text).unwrap(), baseline);
+
+        assert!(Baseline::parse("3 part2").is_err());
+        assert!(Baseline::parse("3 part2 fast").is_err());
+    }
+
+    #[test]
+    fn test_change_percent() {
+        let change =
+            change_percent(Duration::from_millis(200), Duration::from_millis(250)).unwrap();
+
+        assert!((change - 25.0).abs() < 1e-9);
+        assert_eq!(
+            change_percent(Duration::ZERO, Duration::from_millis(1)),
+            None
+        );
+        assert_eq!(change_percent(Duration::ZERO, Duration::ZERO), None);
+    }
+}
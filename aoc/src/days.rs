@@ -0,0 +1,225 @@
+use std::{
+    hint::black_box,
+    io::Write,
+    panic,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_generate::Generated;
+use aoc_visual::{Animated, Animation, Frames, Image, ImageFrames, Pictured};
+
+use crate::bench::{BenchError, Samples};
+
+/// Answers to the requested parts of a day, with the time each phase took.
+#[derive(Debug)]
+pub struct Solved {
+    pub parse_time: Duration,
+    pub answers: Vec<(u8, Result<Answer, SolveError>, Duration)>,
+}
+
+pub type SolveFn = fn(&str, &[u8]) -> Result<Solved, ParseError>;
+
+pub type BenchFn = fn(&str, &[u8], usize) -> Result<Samples, BenchError>;
+
+pub type AnimateFn = fn(&str, u8, &mut Playback) -> Result<Result<Answer, SolveError>, ParseError>;
+
+pub type ImageFn = fn(&str) -> Result<Image, ParseError>;
+
+pub type GenerateFn = fn(u64, usize) -> String;
+
+/// Where the frames of an animation go: played back as text, or collected as images.
+pub enum Playback {
+    Text(Animation<Box<dyn Write>>),
+    Images(ImageFrames),
+}
+
+impl Frames for Playback {
+    fn frame(&mut self, render: impl FnOnce() -> String) {
+        match self {
+            Playback::Text(animation) => animation.frame(render),
+            Playback::Images(frames) => frames.frame(render),
+        }
+    }
+}
+
+pub struct Day {
+    pub number: u8,
+    pub solve: SolveFn,
+    pub bench: BenchFn,
+    /// Makes up a random input of a given size from a seed.
+    pub generate: GenerateFn,
+    /// Solves a part while playing back its simulation, for days that have one.
+    pub animate: Option<AnimateFn>,
+    /// Draws a picture of the puzzle, for days that have one.
+    pub image: Option<ImageFn>,
+}
+
+impl Day {
+    const fn new<S: Generated>(number: u8) -> Self
+    where
+        for<'a> S::Input<'a>: Sync,
+    {
+        Self {
+            number,
+            solve: solve::<S>,
+            bench: bench::<S>,
+            generate: aoc_generate::generate::<S>,
+            animate: None,
+            image: None,
+        }
+    }
+
+    const fn animated<S: Animated + Generated>(number: u8) -> Self
+    where
+        for<'a> S::Input<'a>: Sync,
+    {
+        Self {
+            animate: Some(animate::<S>),
+            ..Self::new::<S>(number)
+        }
+    }
+
+    const fn pictured<S: Pictured>(self) -> Self {
+        Self {
+            image: Some(image::<S>),
+            ..self
+        }
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        self.file_path("input")
+    }
+
+    /// Path of a text file named `name` in this day's directory.
+    pub fn file_path(&self, name: &str) -> PathBuf {
+        workspace_root().join(format!("day{}/{name}.txt", self.number))
+    }
+}
+
+/// Parses `contents` once, then solves each of the requested `parts` on its own thread.
+fn solve<S: Solution>(contents: &str, parts: &[u8]) -> Result<Solved, ParseError>
+where
+    for<'a> S::Input<'a>: Sync,
+{
+    let start = Instant::now();
+    let input = S::parse(contents)?;
+    let parse_time = start.elapsed();
+
+    let day = tracing::Span::current();
+    let answers = thread::scope(|scope| {
+        let handles: Vec<_> = parts
+            .iter()
+            .map(|&part| {
+                let (day, input) = (&day, &input);
+                scope.spawn(move || {
+                    let _span = tracing::info_span!(parent: day, "part", part).entered();
+                    let start = Instant::now();
+                    let answer = match part {
+                        1 => S::part_1(input),
+                        2 => S::part_2(input),
+                        _ => panic!("Invalid part {part}"),
+                    };
+                    (part, answer, start.elapsed())
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|err| panic::resume_unwind(err))
+            })
+            .collect()
+    });
+
+    Ok(Solved {
+        parse_time,
+        answers,
+    })
+}
+
+fn animate<S: Animated>(
+    contents: &str,
+    part: u8,
+    playback: &mut Playback,
+) -> Result<Result<Answer, SolveError>, ParseError> {
+    let input = S::parse(contents)?;
+
+    Ok(S::animate(&input, part, playback))
+}
+
+fn image<S: Pictured>(contents: &str) -> Result<Image, ParseError> {
+    let input = S::parse(contents)?;
+
+    Ok(S::image(&input))
+}
+
+/// Times parsing and each of the requested `parts` separately, `runs` times over, failing if a
+/// part has no answer.
+fn bench<S: Solution>(contents: &str, parts: &[u8], runs: usize) -> Result<Samples, BenchError> {
+    let mut samples = Samples {
+        parse: Vec::with_capacity(runs),
+        parts: parts
+            .iter()
+            .map(|&part| (part, Vec::with_capacity(runs)))
+            .collect(),
+    };
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let input = black_box(S::parse(black_box(contents))?);
+        samples.parse.push(start.elapsed());
+
+        for (part, part_samples) in &mut samples.parts {
+            let start = Instant::now();
+            let answer = black_box(match part {
+                1 => S::part_1(&input),
+                2 => S::part_2(&input),
+                _ => panic!("Invalid part {part}"),
+            });
+            part_samples.push(start.elapsed());
+
+            answer.map_err(|err| BenchError::Solve(*part, err))?;
+        }
+    }
+
+    Ok(samples)
+}
+
+pub const YEAR: u16 = 2022;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day1::Day1>(1),
+    Day::new::<day2::Day2>(2),
+    Day::new::<day3::Day3>(3),
+    Day::new::<day4::Day4>(4),
+    Day::new::<day5::Day5>(5),
+    Day::new::<day6::Day6>(6),
+    Day::new::<day7::Day7>(7),
+    Day::new::<day8::Day8>(8).pictured::<day8::Day8>(),
+    Day::animated::<day9::Day9>(9),
+    Day::animated::<day10::Day10>(10).pictured::<day10::Day10>(),
+    Day::new::<day11::Day11>(11),
+    Day::new::<day12::Day12>(12).pictured::<day12::Day12>(),
+    Day::new::<day13::Day13>(13),
+    Day::animated::<day14::Day14>(14).pictured::<day14::Day14>(),
+    Day::new::<day15::Day15>(15),
+    Day::new::<day16::Day16>(16),
+    Day::animated::<day17::Day17>(17),
+    Day::new::<day18::Day18>(18),
+    Day::new::<day21::Day21>(21),
+];
+
+/// Root of the workspace, so that day directories are found regardless of the working directory.
+pub fn workspace_root() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,215 @@
+use std::{
+    env, fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_core::normalize_line_endings;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the value of the `session` cookie of a logged in user.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding [`DEFAULT_BASE_URL`].
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Downloads puzzle inputs of one year, caching them as `dayN/input.txt` files.
+pub struct Client {
+    agent: ureq::Agent,
+    year: u16,
+    session: String,
+    base_url: String,
+    cache_dir: PathBuf,
+    /// Minimum time between two requests to the server.
+    min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(year: u16, session: impl Into<String>) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .user_agent(concat!("aoc-runner/", env!("CARGO_PKG_VERSION")))
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Self {
+            agent,
+            year,
+            session: session.into(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            cache_dir: PathBuf::from("."),
+            min_interval: Duration::from_secs(5),
+            last_request: None,
+        }
+    }
+
+    /// Creates a client with the session token and base URL taken from the environment.
+    pub fn from_env(year: u16) -> Result<Self, String> {
+        let session = env::var(SESSION_VAR)
+            .map_err(|_| format!("Set {SESSION_VAR} to your session cookie to fetch inputs"))?;
+
+        let client = Self::new(year, session.trim());
+        Ok(match env::var(BASE_URL_VAR) {
+            Ok(base_url) => client.base_url(base_url),
+            Err(_) => client,
+        })
+    }
+
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Directory containing a `dayN` directory per day to cache inputs in.
+    pub fn cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = cache_dir.into();
+        self
+    }
+
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day{day}/input.txt"))
+    }
+
+    /// Returns the path of the cached input of `day`, downloading it first if it is not cached yet.
+    pub fn fetch(&mut self, day: u8) -> Result<PathBuf, String> {
+        let path = self.cache_path(day);
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let input = self.download(day)?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|err| format!("Failed to create {}: {err}", dir.display()))?;
+        }
+        fs::write(&path, input)
+            .map_err(|err| format!("Failed to write {}: {err}", path.display()))?;
+
+        Ok(path)
+    }
+
+    fn download(&mut self, day: u8) -> Result<String, String> {
+        if let Some(last_request) = self.last_request {
+            thread::sleep(self.min_interval.saturating_sub(last_request.elapsed()));
+        }
+        self.last_request = Some(Instant::now());
+
+        let url = format!("{}/{}/day/{day}/input", self.base_url, self.year);
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .call();
+
+        let input = match response {
+            Ok(mut response) => response.body_mut().read_to_string(),
+            Err(err) => Err(err),
+        };
+
+        input.map(normalize_line_endings).map_err(|err| match err {
+            ureq::Error::StatusCode(404) => format!("Input of day {day} is not available yet"),
+            ureq::Error::StatusCode(400 | 500) => {
+                format!("Failed to fetch {url}, is the session token still valid?")
+            }
+            err => format!("Failed to fetch {url}: {err}"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use tiny_http::{Response, Server};
+
+    use super::*;
+
+    /// Serves inputs like the real site would, counting the requests it receives.
+    fn serve() -> (String, Arc<AtomicUsize>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let counter = Arc::clone(&requests);
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let authorized = request
+                    .headers()
+                    .iter()
+                    .any(|header| header.field.equiv("Cookie") && header.value == "session=secret");
+                let response = match request.url() {
+                    _ if !authorized => Response::from_string("Unauthorized").with_status_code(400),
+                    "/2022/day/1/input" => Response::from_string("1000\r\n2000\r\n"),
+                    "/2022/day/2/input" => Response::from_string("A Y\n"),
+                    _ => Response::from_string("Not found").with_status_code(404),
+                };
+                request.respond(response).unwrap();
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    fn client(base_url: &str, cache_dir: &tempfile::TempDir) -> Client {
+        let mut client = Client::new(2022, "secret")
+            .base_url(base_url)
+            .cache_dir(cache_dir.path());
+        client.min_interval = Duration::ZERO;
+        client
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (base_url, requests) = serve();
+        let cache_dir = tempfile::tempdir().unwrap();
+        let mut client = client(&base_url, &cache_dir);
+
+        let path = client.fetch(1).unwrap();
+
+        assert_eq!(path, cache_dir.path().join("day1/input.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        assert_eq!(client.fetch(1).unwrap(), path);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (base_url, _) = serve();
+        let cache_dir = tempfile::tempdir().unwrap();
+
+        let err = client(&base_url, &cache_dir).fetch(25).unwrap_err();
+        assert_eq!(err, "Input of day 25 is not available yet");
+        assert!(!client(&base_url, &cache_dir).cache_path(25).exists());
+
+        let mut client = Client::new(2022, "expired")
+            .base_url(&base_url)
+            .cache_dir(cache_dir.path());
+        assert!(client.fetch(1).unwrap_err().contains("session token"));
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let (base_url, requests) = serve();
+        let cache_dir = tempfile::tempdir().unwrap();
+        let mut client = client(&base_url, &cache_dir);
+        client.min_interval = Duration::from_millis(300);
+
+        let start = Instant::now();
+        client.fetch(1).unwrap();
+        client.fetch(2).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,661 @@
+mod bench;
+mod days;
+mod fetch;
+mod output;
+mod pool;
+mod scaffold;
+mod verify;
+
+use std::{
+    fs,
+    io::{self, Write},
+    num::NonZeroUsize,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+
+use aoc_core::{read_input, ParseError};
+use aoc_generate::DEFAULT_SEED;
+use aoc_visual::{default_palette, Animation, Image, ImageFrames};
+use clap::Parser;
+
+use bench::{Baseline, BenchError, Stats};
+use days::{Day, Playback, Solved, DAYS, YEAR};
+use fetch::Client;
+use output::{Format, Record};
+use pool::Outcome;
+
+/// Runs Advent of Code 2022 solutions for the selected days.
+#[derive(Debug, Parser)]
+struct Args {
+    /// Days to run: a single day (`5`), a range (`3-7`) or `all`
+    #[arg(default_value = "all", value_parser = parse_days)]
+    days: Vec<RangeInclusive<u8>>,
+
+    /// Read puzzle input from this file instead of the day's `input.txt`, or from stdin if `-`
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Run only the given part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Output format of answers and timings
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Number of days to solve concurrently, each solving its parts in parallel, defaults to the
+    /// number of CPUs
+    #[arg(short, long)]
+    jobs: Option<NonZeroUsize>,
+
+    /// Give up on a day after this many seconds
+    #[arg(long, value_name = "SECONDS")]
+    timeout: Option<u64>,
+
+    /// Download inputs of the selected days that are missing, using the session token in `AOC_SESSION`
+    #[arg(long, conflicts_with = "input")]
+    fetch: bool,
+
+    /// Create a new day crate from the template and register it with the workspace and runner
+    #[arg(long, value_name = "DAY", value_parser = clap::value_parser!(u8).range(1..=25), exclusive = true)]
+    new: Option<u8>,
+
+    /// Play back the simulation of a single day, for days that have one
+    #[arg(long, conflicts_with_all = ["verify", "bench", "fetch"])]
+    animate: bool,
+
+    /// Frames per second to play the animation at
+    #[arg(long, default_value_t = 20.0, requires = "animate", value_parser = |s: &str| {
+        match s.parse::<f64>() {
+            Ok(fps) if fps > 0.0 && fps.is_finite() => Ok(fps),
+            _ => Err(format!("`{s}` is not a positive number of frames per second")),
+        }
+    })]
+    fps: f64,
+
+    /// Show only every N-th frame of the animation
+    #[arg(long, value_name = "N", default_value_t = 1, requires = "animate")]
+    every: usize,
+
+    /// Write the frames of the animation to this file instead of playing them back, as images
+    /// if it ends in `.png` or `.ppm`
+    #[arg(long, value_name = "FILE", requires = "animate")]
+    headless: Option<PathBuf>,
+
+    /// Most frames to save as images, each of which is kept in memory until all are written
+    #[arg(long, value_name = "N", default_value_t = 200, requires = "headless")]
+    max_frames: usize,
+
+    /// Save a picture of a single day's puzzle to this `.png` or `.ppm` file, for days that have one
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["animate", "verify", "bench"])]
+    image: Option<PathBuf>,
+
+    /// Solve a random input with about SIZE of the day's items instead of the puzzle input
+    #[arg(long, value_name = "SIZE", conflicts_with_all = ["input", "fetch", "verify"])]
+    generate: Option<usize>,
+
+    /// Seed of the random inputs, which are the same for the same seed
+    #[arg(long, default_value_t = DEFAULT_SEED, requires = "generate")]
+    seed: u64,
+
+    /// Check answers against the ones recorded in each day's `answers.txt`
+    #[arg(long, conflicts_with = "bench")]
+    verify: bool,
+
+    /// Time parsing and solving of each day over repeated runs
+    #[arg(long)]
+    bench: bool,
+
+    /// Number of runs to time each day over
+    #[arg(long, default_value_t = 10, requires = "bench")]
+    runs: usize,
+
+    /// Compare timings with a baseline saved earlier and flag regressions
+    #[arg(long, requires = "bench")]
+    baseline: Option<PathBuf>,
+
+    /// Save mean timings as a baseline for later comparisons
+    #[arg(long, requires = "bench")]
+    save_baseline: Option<PathBuf>,
+
+    /// Slowdown compared to the baseline, in percent, above which a phase counts as regressed
+    #[arg(long, default_value_t = 10.0, requires = "baseline")]
+    threshold: f64,
+
+    /// Log filter such as `debug` or `day15=debug,day17=trace`, logging to stderr. Defaults to
+    /// `AOC_LOG`, with nothing logged if that is unset too
+    #[arg(long, value_name = "FILTER")]
+    log: Option<String>,
+}
+
+/// A single day, parsed once and then solved for each of the selected parts in parallel, so
+/// that days can run concurrently too.
+struct Job {
+    day: &'static Day,
+    parts: Arc<[u8]>,
+    contents: String,
+}
+
+impl Job {
+    fn solve(&self) -> Result<Solved, ParseError> {
+        let _span = tracing::info_span!("solve", day = self.day.number).entered();
+        (self.day.solve)(&self.contents, &self.parts)
+    }
+}
+
+/// Puzzle input of `day`: a generated one, the given input file or the day's `input.txt`.
+fn puzzle_input(day: &Day, args: &Args) -> Result<String, String> {
+    if let Some(size) = args.generate {
+        return Ok((day.generate)(args.seed, size));
+    }
+
+    let path = args.input.clone().unwrap_or_else(|| day.input_path());
+    read_input(&path)
+        .map_err(|err| format!("Failed to read puzzle input for day {}: {err}", day.number))
+}
+
+fn parse_days(s: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse_day = |day: &str| {
+        day.trim()
+            .parse::<u8>()
+            .map_err(|_| format!("Invalid day `{day}`"))
+    };
+
+    if s == "all" {
+        return Ok(1..=25);
+    }
+
+    match s.split_once('-').or_else(|| s.split_once("..")) {
+        Some((start, end)) => Ok(parse_day(start)?..=parse_day(end)?),
+        None => parse_day(s).map(|day| day..=day),
+    }
+}
+
+fn select_days(ranges: &[RangeInclusive<u8>]) -> Result<Vec<&'static Day>, String> {
+    if let Some(missing) = ranges
+        .iter()
+        .find(|range| range.start() == range.end() && days::find(*range.start()).is_none())
+    {
+        return Err(format!("Day {} is not available", missing.start()));
+    }
+
+    Ok(DAYS
+        .iter()
+        .filter(|day| ranges.iter().any(|range| range.contains(&day.number)))
+        .collect())
+}
+
+fn print_answer(part: u8, answer: &str) {
+    if answer.contains('\n') {
+        println!("Part {part}:\n{}", answer.trim_end());
+    } else {
+        println!("Part {part}: {answer}");
+    }
+}
+
+/// Verifies recorded answers of `days`, returning whether all of them are still correct.
+fn verify_days(days: &[&Day], part: Option<u8>) -> bool {
+    let (mut passed, mut failed) = (0, 0);
+    for day in days {
+        println!("Day {}", day.number);
+
+        let expected = match verify::expected_answers(day) {
+            Ok(expected) => expected,
+            Err(err) => {
+                println!("{err}");
+                failed += 1;
+                continue;
+            }
+        };
+
+        let expected: Vec<_> = expected
+            .into_iter()
+            .filter(|e| part.is_none_or(|part| e.part == part))
+            .collect();
+
+        for check in verify::verify(day, &expected) {
+            if check.outcome == verify::Outcome::Correct {
+                passed += 1;
+            } else {
+                failed += 1;
+            }
+            println!("{check}");
+        }
+    }
+
+    println!("\n{passed} passed, {failed} failed");
+    failed == 0
+}
+
+/// Solves one part of `day` while playing back its simulation.
+fn animate_day(day: &Day, args: &Args) -> Result<(), String> {
+    let animate = day
+        .animate
+        .ok_or_else(|| format!("Day {} has no animation", day.number))?;
+
+    let contents = puzzle_input(day, args)?;
+
+    let mut playback = match &args.headless {
+        Some(path) if is_image(path) => Playback::Images(
+            ImageFrames::new(FRAME_SCALE, default_palette, args.max_frames).every(args.every),
+        ),
+        Some(path) => {
+            let file = fs::File::create(path)
+                .map_err(|err| format!("Failed to create {}: {err}", path.display()))?;
+            Playback::Text(
+                Animation::headless(Box::new(io::BufWriter::new(file)) as Box<dyn Write>)
+                    .every(args.every),
+            )
+        }
+        None => Playback::Text(
+            Animation::terminal(Box::new(io::stdout()) as Box<dyn Write>)
+                .fps(args.fps)
+                .every(args.every),
+        ),
+    };
+
+    let part = args.part.unwrap_or(1);
+    let answer = animate(&contents, part, &mut playback)
+        .map_err(|err| format!("Failed to parse puzzle input for day {}: {err}", day.number))?;
+    match playback {
+        Playback::Text(animation) => {
+            animation
+                .finish()
+                .map_err(|err| format!("Failed to write animation: {err}"))?;
+        }
+        Playback::Images(frames) => {
+            let path = args
+                .headless
+                .as_deref()
+                .expect("Images are only collected headless");
+            let frames = frames.into_frames().map_err(|err| {
+                format!("Failed to save animation: {err}, show fewer frames with --every or raise --max-frames")
+            })?;
+            let delay = Duration::from_secs_f64(1.0 / args.fps);
+            write_images(path, &frames, delay)?;
+        }
+    }
+
+    let answer =
+        answer.map_err(|err| format!("Day {} part {part} has no answer: {err}", day.number))?;
+    println!("Day {}", day.number);
+    print_answer(part, &answer.to_string());
+
+    Ok(())
+}
+
+/// Pixels per character of frames that are saved as images.
+const FRAME_SCALE: usize = 4;
+
+/// Saves a picture of `day`'s puzzle to `path`.
+fn picture_day(day: &Day, args: &Args, path: &Path) -> Result<(), String> {
+    let image = day
+        .image
+        .ok_or_else(|| format!("Day {} has no picture", day.number))?;
+
+    let contents = puzzle_input(day, args)?;
+
+    let image = image(&contents)
+        .map_err(|err| format!("Failed to parse puzzle input for day {}: {err}", day.number))?;
+    write_images(path, &[image], Duration::ZERO)
+}
+
+fn is_image(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("png" | "ppm")
+    )
+}
+
+/// Writes `frames` to `path` in the format given by its extension, animated if there are several.
+fn write_images(path: &Path, frames: &[Image], delay: Duration) -> Result<(), String> {
+    if !is_image(path) {
+        return Err(format!(
+            "Unsupported image format of {}, use .png or .ppm",
+            path.display()
+        ));
+    }
+
+    let file = fs::File::create(path)
+        .map_err(|err| format!("Failed to create {}: {err}", path.display()))?;
+    let mut out = io::BufWriter::new(file);
+
+    if path.extension().is_some_and(|ext| ext == "ppm") {
+        aoc_visual::write_ppm_frames(frames, &mut out)
+    } else {
+        aoc_visual::write_png(frames, delay, &mut out)
+    }
+    .and_then(|()| out.flush())
+    .map_err(|err| format!("Failed to write {}: {err}", path.display()))
+}
+
+fn fetch_inputs(days: &[&Day]) -> Result<(), String> {
+    let mut client = Client::from_env(YEAR)?.cache_dir(days::workspace_root());
+    for day in days {
+        client.fetch(day.number)?;
+    }
+
+    Ok(())
+}
+
+/// Benchmarks `days`, returning whether none of them regressed compared to the baseline.
+fn bench_days(days: &[&Day], parts: &[u8], args: &Args) -> Result<bool, String> {
+    let baseline = match &args.baseline {
+        Some(path) => {
+            let text = fs::read_to_string(path)
+                .map_err(|err| format!("Failed to read baseline {}: {err}", path.display()))?;
+            Some(Baseline::parse(&text)?)
+        }
+        None => None,
+    };
+
+    let mut new_baseline = Baseline::default();
+    let mut regressions = 0;
+    for day in days {
+        let contents = puzzle_input(day, args)?;
+
+        let samples = (day.bench)(&contents, parts, args.runs.max(1)).map_err(|err| match err {
+            BenchError::Parse(err) => {
+                format!("Failed to parse puzzle input for day {}: {err}", day.number)
+            }
+            BenchError::Solve(part, err) => {
+                format!("Day {} part {part} has no answer: {err}", day.number)
+            }
+        })?;
+
+        println!("Day {}", day.number);
+        for (phase, phase_samples) in samples.phases() {
+            let stats = Stats::new(phase_samples);
+            new_baseline.insert(day.number, &phase, stats.mean);
+
+            let comparison = baseline
+                .as_ref()
+                .and_then(|baseline| baseline.get(day.number, &phase))
+                .map(
+                    |old_mean| match bench::change_percent(old_mean, stats.mean) {
+                        Some(change) if change > args.threshold => {
+                            regressions += 1;
+                            format!("  {change:+.1}% REGRESSION")
+                        }
+                        Some(change) => format!("  {change:+.1}%"),
+                        None => "  no baseline timing to compare with".to_string(),
+                    },
+                )
+                .unwrap_or_default();
+
+            println!("{phase:<6} {stats}{comparison}");
+        }
+    }
+
+    if let Some(path) = &args.save_baseline {
+        fs::write(path, new_baseline.to_string())
+            .map_err(|err| format!("Failed to save baseline {}: {err}", path.display()))?;
+    }
+
+    if baseline.is_some() {
+        println!("\n{regressions} regression(s) above {}%", args.threshold);
+    }
+
+    Ok(regressions == 0)
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    if let Err(err) = aoc_core::init_logging(args.log.as_deref()) {
+        eprintln!("{err}");
+        return ExitCode::FAILURE;
+    }
+
+    if let Some(day) = args.new {
+        return match scaffold::new_day(days::workspace_root(), day) {
+            Ok(()) => {
+                println!(
+                    "Created day{day}, add its example to day{day}/example.txt to get started"
+                );
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let days = match select_days(&args.days) {
+        Ok(days) => days,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if args.input.is_some() && days.len() != 1 {
+        eprintln!("An input file can only be given when running a single day");
+        return ExitCode::FAILURE;
+    }
+
+    if args.fetch {
+        if let Err(err) = fetch_inputs(&days) {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if args.animate {
+        let [day] = days[..] else {
+            eprintln!("Only a single day can be animated at a time");
+            return ExitCode::FAILURE;
+        };
+
+        return match animate_day(day, &args) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    if let Some(path) = &args.image {
+        let [day] = days[..] else {
+            eprintln!("Only a single day can be pictured at a time");
+            return ExitCode::FAILURE;
+        };
+
+        return match picture_day(day, &args, path) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    if args.verify {
+        return if verify_days(&days, args.part) {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        };
+    }
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    if args.bench {
+        return match bench_days(&days, &parts, &args) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let parts: Arc<[u8]> = parts.into();
+    let mut jobs = Vec::new();
+    for day in days {
+        let contents = match puzzle_input(day, &args) {
+            Ok(contents) => contents,
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        };
+
+        jobs.push(Job {
+            day,
+            parts: Arc::clone(&parts),
+            contents,
+        });
+    }
+
+    let workers = args
+        .jobs
+        .or_else(|| thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get);
+    let timeout = args.timeout.map(Duration::from_secs);
+
+    let mut records = Vec::new();
+    let mut failed = false;
+    pool::run_ordered(jobs, workers, timeout, Job::solve, |job, outcome| {
+        if args.format == Format::Text {
+            println!("Day {}", job.day.number);
+        }
+
+        let solved = match outcome {
+            Outcome::Done(Ok(solved)) => solved,
+            Outcome::Done(Err(err)) => {
+                eprintln!(
+                    "Failed to parse puzzle input for day {}: {err}",
+                    job.day.number
+                );
+                failed = true;
+                return;
+            }
+            Outcome::Panicked => {
+                eprintln!("Day {} panicked", job.day.number);
+                failed = true;
+                return;
+            }
+            Outcome::TimedOut => {
+                eprintln!(
+                    "Day {} timed out after {:?}",
+                    job.day.number,
+                    timeout.unwrap_or_default()
+                );
+                failed = true;
+                return;
+            }
+        };
+
+        for (part, answer, part_time) in solved.answers {
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(err) => {
+                    eprintln!("Day {} part {part} has no answer: {err}", job.day.number);
+                    failed = true;
+                    continue;
+                }
+            };
+
+            match args.format {
+                Format::Text => print_answer(part, &answer.to_string()),
+                _ => records.push(Record::new(
+                    job.day.number,
+                    part,
+                    answer,
+                    solved.parse_time,
+                    part_time,
+                )),
+            }
+        }
+    });
+
+    match args.format {
+        Format::Text => {}
+        Format::Json => println!("{}", output::to_json(&records)),
+        Format::Csv => print!("{}", output::to_csv(&records)),
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("5"), Ok(5..=5));
+        assert_eq!(parse_days("3-7"), Ok(3..=7));
+        assert_eq!(parse_days("3..7"), Ok(3..=7));
+        assert_eq!(parse_days("all"), Ok(1..=25));
+        assert!(parse_days("five").is_err());
+    }
+
+    #[test]
+    fn test_fps() {
+        let fps = |value: &str| {
+            Args::try_parse_from(["aoc", "17", "--animate", "--fps", value]).map(|args| args.fps)
+        };
+
+        assert_eq!(fps("2.5").unwrap(), 2.5);
+        for value in ["0", "-1", "inf", "NaN", "fast"] {
+            assert!(fps(value).is_err());
+        }
+    }
+
+    #[test]
+    fn test_select_days() {
+        let numbers = |days: Vec<&Day>| days.iter().map(|day| day.number).collect::<Vec<_>>();
+        let registered = |range: RangeInclusive<u8>| {
+            DAYS.iter()
+                .map(|day| day.number)
+                .filter(|number| range.contains(number))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            numbers(select_days(&[1..=3, 21..=25]).unwrap()),
+            [registered(1..=3), registered(21..=25)].concat()
+        );
+        assert_eq!(numbers(select_days(&[1..=25]).unwrap()), registered(1..=25));
+
+        for day in 1..=25 {
+            assert_eq!(select_days(&[day..=day]).is_ok(), days::find(day).is_some());
+        }
+        assert!(select_days(&[26..=26]).is_err());
+        assert!(select_days(&[26..=30]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_write_images() {
+        let dir = tempfile::tempdir().unwrap();
+        let frames = [Image::new(2, 1, [255, 0, 0]), Image::new(2, 1, [0, 0, 255])];
+
+        let ppm = dir.path().join("frames.ppm");
+        write_images(&ppm, &frames, Duration::ZERO).unwrap();
+        assert!(fs::read(&ppm).unwrap().starts_with(b"P6\n2 1\n255\n"));
+
+        let png = dir.path().join("frames.png");
+        write_images(&png, &frames, Duration::ZERO).unwrap();
+        assert!(fs::read(&png).unwrap().starts_with(b"\x89PNG"));
+
+        assert!(!is_image(Path::new("frames.txt")));
+        assert!(write_images(&dir.path().join("frames.gif"), &frames, Duration::ZERO).is_err());
+    }
+}
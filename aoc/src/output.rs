@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use aoc_core::Answer;
+use clap::ValueEnum;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+/// Answer to one part of a day, with the time spent parsing the input and solving the part.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub parse_ns: u128,
+    pub part_ns: u128,
+}
+
+impl Record {
+    pub fn new(
+        day: u8,
+        part: u8,
+        answer: Answer,
+        parse_time: Duration,
+        part_time: Duration,
+    ) -> Self {
+        let (answer, kind) = match answer {
+            Answer::Integer(int) => (Answer::Integer(int), "integer"),
+            Answer::Text(text) => (Answer::Text(text.trim_end().to_string()), "text"),
+        };
+
+        Self {
+            day,
+            part,
+            answer,
+            kind,
+            parse_ns: parse_time.as_nanos(),
+            part_ns: part_time.as_nanos(),
+        }
+    }
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).expect("Records are always serializable")
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv = String::from("day,part,answer,type,parse_ns,part_ns\n");
+    for record in records {
+        csv += &format!(
+            "{},{},{},{},{},{}\n",
+            record.day,
+            record.part,
+            csv_field(&record.answer.to_string()),
+            record.kind,
+            record.parse_ns,
+            record.part_ns
+        );
+    }
+
+    csv
+}
+
+/// Quotes `field` if it contains characters that would otherwise break up the CSV row.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record::new(
+                1,
+                1,
+                Answer::Integer(24000),
+                Duration::from_micros(5),
+                Duration::from_nanos(300),
+            ),
+            Record::new(
+                10,
+                2,
+                Answer::from("##..\n.##.\n"),
+                Duration::from_micros(2),
+                Duration::from_micros(1),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json: serde_json::Value = serde_json::from_str(&to_json(&records())).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!([
+                { "day": 1, "part": 1, "answer": 24000, "type": "integer", "parse_ns": 5000, "part_ns": 300 },
+                { "day": 10, "part": 2, "answer": "##..\n.##.", "type": "text", "parse_ns": 2000, "part_ns": 1000 },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&records()),
+            "day,part,answer,type,parse_ns,part_ns
+1,1,24000,integer,5000,300
+10,2,\"##..\n.##.\",text,2000,1000
+"
+        );
+    }
+}
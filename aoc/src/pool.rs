@@ -0,0 +1,225 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// How a task run by [`run_ordered`] ended.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome<R> {
+    Done(R),
+    Panicked,
+    TimedOut,
+}
+
+/// Messages sent by workers about the task at the given index.
+enum Message<R> {
+    Started(usize, Instant),
+    Finished(usize, Outcome<R>),
+}
+
+/// Where a task is at, as seen by the thread reporting outcomes.
+enum State<R> {
+    Waiting,
+    Running(Instant),
+    Ended(Outcome<R>),
+    Reported,
+}
+
+/// Runs `work` on every task using `workers` threads, reporting outcomes in the order of `tasks`.
+///
+/// Tasks still running once `timeout` has passed are reported as timed out straight away. They
+/// cannot be stopped though, so their worker is replaced by a new one to run the remaining tasks,
+/// and is left running in the background until the task finishes.
+pub fn run_ordered<T, R>(
+    tasks: Vec<T>,
+    workers: usize,
+    timeout: Option<Duration>,
+    work: fn(&T) -> R,
+    mut report: impl FnMut(&T, Outcome<R>),
+) where
+    T: Send + Sync + 'static,
+    R: Send + 'static,
+{
+    let tasks = Arc::new(tasks);
+    let next = Arc::new(AtomicUsize::new(0));
+    let (sender, receiver) = mpsc::channel();
+
+    for _ in 0..workers.clamp(1, tasks.len().max(1)) {
+        spawn_worker(&tasks, &next, &sender, work);
+    }
+
+    let mut states: Vec<State<R>> = (0..tasks.len()).map(|_| State::Waiting).collect();
+    let mut reported = 0;
+    while reported < tasks.len() {
+        let deadline = states
+            .iter()
+            .filter_map(|state| match state {
+                State::Running(deadline) => Some(*deadline),
+                _ => None,
+            })
+            .min();
+
+        let message = match deadline {
+            Some(deadline) => {
+                match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    Ok(message) => Some(message),
+                    Err(mpsc::RecvTimeoutError::Timeout) => None,
+                    Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                }
+            }
+            None => match receiver.recv() {
+                Ok(message) => Some(message),
+                Err(mpsc::RecvError) => break,
+            },
+        };
+
+        match message {
+            // Without a timeout, tasks are never given up on, so there is nothing to track.
+            Some(Message::Started(index, started)) => {
+                if let Some(timeout) = timeout {
+                    states[index] = State::Running(started + timeout);
+                }
+            }
+            Some(Message::Finished(index, outcome)) => {
+                // Tasks that already timed out stay that way, even if they finish later on.
+                if matches!(states[index], State::Waiting | State::Running(_)) {
+                    states[index] = State::Ended(outcome);
+                }
+            }
+            None => {
+                let now = Instant::now();
+                for state in &mut states {
+                    if matches!(state, State::Running(deadline) if *deadline <= now) {
+                        *state = State::Ended(Outcome::TimedOut);
+                        if next.load(Ordering::Relaxed) < tasks.len() {
+                            spawn_worker(&tasks, &next, &sender, work);
+                        }
+                    }
+                }
+            }
+        }
+
+        while let Some(state) = states.get_mut(reported) {
+            match std::mem::replace(state, State::Reported) {
+                State::Ended(outcome) => report(&tasks[reported], outcome),
+                other => {
+                    *state = other;
+                    break;
+                }
+            }
+            reported += 1;
+        }
+    }
+}
+
+/// Spawns a thread running tasks until there are none left to start.
+fn spawn_worker<T, R>(
+    tasks: &Arc<Vec<T>>,
+    next: &Arc<AtomicUsize>,
+    sender: &mpsc::Sender<Message<R>>,
+    work: fn(&T) -> R,
+) where
+    T: Send + Sync + 'static,
+    R: Send + 'static,
+{
+    let tasks = Arc::clone(tasks);
+    let next = Arc::clone(next);
+    let sender = sender.clone();
+
+    thread::spawn(move || loop {
+        let index = next.fetch_add(1, Ordering::Relaxed);
+        if index >= tasks.len() {
+            break;
+        }
+
+        if sender
+            .send(Message::Started(index, Instant::now()))
+            .is_err()
+        {
+            break;
+        }
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| work(&tasks[index])))
+            .map_or(Outcome::Panicked, Outcome::Done);
+        if sender.send(Message::Finished(index, outcome)).is_err() {
+            break;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sleep_then_double(millis: &u64) -> u64 {
+        thread::sleep(Duration::from_millis(*millis));
+        assert!(*millis != 13, "Unlucky");
+        millis * 2
+    }
+
+    #[test]
+    fn test_run_ordered() {
+        let mut outcomes = Vec::new();
+        run_ordered(
+            vec![30, 1, 20, 13, 5],
+            3,
+            None,
+            sleep_then_double,
+            |&task, outcome| outcomes.push((task, outcome)),
+        );
+
+        assert_eq!(
+            outcomes,
+            vec![
+                (30, Outcome::Done(60)),
+                (1, Outcome::Done(2)),
+                (20, Outcome::Done(40)),
+                (13, Outcome::Panicked),
+                (5, Outcome::Done(10)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_run_ordered_timeout() {
+        let mut outcomes = Vec::new();
+        let timeout = Some(Duration::from_millis(200));
+        let start = Instant::now();
+        run_ordered(
+            vec![5000, 1, 2],
+            2,
+            timeout,
+            sleep_then_double,
+            |_, outcome| outcomes.push(outcome),
+        );
+
+        // The timed out task keeps its worker busy, but the other workers run the rest.
+        assert!(start.elapsed() < Duration::from_millis(2000));
+        assert_eq!(
+            outcomes,
+            vec![Outcome::TimedOut, Outcome::Done(2), Outcome::Done(4)]
+        );
+    }
+
+    #[test]
+    fn test_run_ordered_timeout_single_worker() {
+        let mut outcomes = Vec::new();
+        let timeout = Some(Duration::from_millis(200));
+        let start = Instant::now();
+        run_ordered(
+            vec![5000, 1],
+            1,
+            timeout,
+            sleep_then_double,
+            |_, outcome| outcomes.push(outcome),
+        );
+
+        // The only worker is stuck on the first task, so a new one runs the second.
+        assert!(start.elapsed() < Duration::from_millis(2000));
+        assert_eq!(outcomes, vec![Outcome::TimedOut, Outcome::Done(2)]);
+    }
+}
@@ -0,0 +1,286 @@
+use std::{fs, path::Path};
+
+/// Creates `dayN` from the `template` crate under `root`, adds it to the workspace members,
+/// registers it with the runner and gives it a fuzz target.
+pub fn new_day(root: &Path, day: u8) -> Result<(), String> {
+    let dir = root.join(format!("day{day}"));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    copy_template(&root.join("template"), &dir, day)?;
+
+    update_file(&root.join("Cargo.toml"), |text| {
+        insert_entry(text, day, &format!("    \"day{day}\","), |line| {
+            line.trim()
+                .strip_prefix("\"day")?
+                .strip_suffix("\",")?
+                .parse()
+                .ok()
+        })
+    })?;
+
+    update_file(&root.join("aoc/Cargo.toml"), |text| {
+        let dependency = format!("day{day} = {{ path = \"../day{day}\" }}");
+        insert_entry(text, day, &dependency, |line| {
+            line.strip_prefix("day")?.split_once(' ')?.0.parse().ok()
+        })
+    })?;
+
+    update_file(&root.join("aoc/src/days.rs"), |text| {
+        let entry = format!("    Day::new::<day{day}::Day{day}>({day}),");
+        insert_entry(text, day, &entry, |line| {
+            line.trim()
+                .strip_prefix("Day::")?
+                .split_once("::<day")?
+                .1
+                .split_once(':')?
+                .0
+                .parse()
+                .ok()
+        })
+    })?;
+
+    add_fuzz_target(&root.join("fuzz"), day)
+}
+
+/// Adds a fuzz target for `dayN` to the `fuzz` crate.
+fn add_fuzz_target(fuzz: &Path, day: u8) -> Result<(), String> {
+    update_file(&fuzz.join("Cargo.toml"), |text| {
+        let dependency = format!("day{day} = {{ path = \"../day{day}\" }}");
+        let text = insert_entry(text, day, &dependency, |line| {
+            line.strip_prefix("day")?.split_once(' ')?.0.parse().ok()
+        })?;
+
+        Ok(format!(
+            "{text}\n[[bin]]\nname = \"day{day}\"\npath = \"fuzz_targets/day{day}.rs\"\ntest = false\ndoc = false\n"
+        ))
+    })?;
+
+    let target = fuzz.join(format!("fuzz_targets/day{day}.rs"));
+    let source = format!(
+        "#![no_main]\n\nuse libfuzzer_sys::fuzz_target;\n\nfuzz_target!(|input: &str| aoc_fuzz::parse_and_solve::<day{day}::Day{day}>(input));\n"
+    );
+    fs::write(&target, source).map_err(|err| format!("Failed to write {}: {err}", target.display()))
+}
+
+/// Copies every file in `template` to `dir`, filling in the cargo-generate placeholders.
+fn copy_template(template: &Path, dir: &Path, day: u8) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|err| format!("Failed to create {}: {err}", dir.display()))?;
+
+    let entries = fs::read_dir(template)
+        .map_err(|err| format!("Failed to read {}: {err}", template.display()))?;
+    for entry in entries {
+        let path = entry.map_err(|err| err.to_string())?.path();
+        let target = dir.join(path.file_name().expect("Directory entries have a name"));
+
+        if path.is_dir() {
+            copy_template(&path, &target, day)?;
+        } else {
+            let text = fs::read_to_string(&path)
+                .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+            fs::write(&target, render(&text, day))
+                .map_err(|err| format!("Failed to write {}: {err}", target.display()))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn update_file(
+    path: &Path,
+    update: impl FnOnce(&str) -> Result<String, String>,
+) -> Result<(), String> {
+    let text = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+    let text =
+        update(&text).map_err(|err| format!("Failed to update {}: {err}", path.display()))?;
+
+    fs::write(path, text).map_err(|err| format!("Failed to write {}: {err}", path.display()))
+}
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{crate_name | pascal_case}}", &format!("Day{day}"))
+        .replace("{{crate_name}}", &format!("day{day}"))
+        .replace("{{project-name}}", &format!("day{day}"))
+}
+
+/// Inserts `entry` into a list of lines for which `day_of` returns a day number, keeping it
+/// sorted by day.
+fn insert_entry(
+    text: &str,
+    day: u8,
+    entry: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Result<String, String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| day_of(line).map(|day| (i, day)))
+        .collect();
+
+    if days.iter().any(|&(_, other)| other == day) {
+        return Err(format!("day {day} is already listed"));
+    }
+
+    let index = match days.iter().find(|&&(_, other)| other > day) {
+        Some(&(i, _)) => i,
+        None => match days.last() {
+            Some(&(i, _)) => i + 1,
+            None => return Err("no days are listed to insert the new day next to".to_string()),
+        },
+    };
+
+    let mut lines = lines;
+    lines.insert(index, entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::workspace_root;
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let template = "use {{crate_name}}::*;\nstruct {{crate_name | pascal_case}};\nname = \"{{project-name}}\"";
+
+        assert_eq!(
+            render(template, 19),
+            "use day19::*;\nstruct Day19;\nname = \"day19\""
+        );
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day1\",\n    \"day21\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\nclap = \"4\"\nday1 = { path = \"../day1\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/days.rs"),
+            "pub const DAYS: &[Day] = &[\n    Day::new::<day1::Day1>(1),\n    Day::animated::<day21::Day21>(21),\n];\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("fuzz/fuzz_targets")).unwrap();
+        fs::write(
+            root.join("fuzz/Cargo.toml"),
+            "[dependencies]\nday1 = { path = \"../day1\" }\n\n[[bin]]\nname = \"day1\"\n",
+        )
+        .unwrap();
+        copy_dir(&workspace_root().join("template"), &root.join("template"));
+
+        new_day(root, 19).unwrap();
+
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        assert_eq!(
+            read("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day1\",\n    \"day19\",\n    \"day21\",\n]\n"
+        );
+        assert_eq!(
+            read("aoc/Cargo.toml"),
+            "[dependencies]\nclap = \"4\"\nday1 = { path = \"../day1\" }\nday19 = { path = \"../day19\" }\n"
+        );
+        assert_eq!(
+            read("aoc/src/days.rs"),
+            "pub const DAYS: &[Day] = &[\n    Day::new::<day1::Day1>(1),\n    Day::new::<day19::Day19>(19),\n    Day::animated::<day21::Day21>(21),\n];\n"
+        );
+
+        assert_eq!(
+            read("fuzz/Cargo.toml"),
+            "[dependencies]\nday1 = { path = \"../day1\" }\nday19 = { path = \"../day19\" }\n\n[[bin]]\nname = \"day1\"\n\n[[bin]]\nname = \"day19\"\npath = \"fuzz_targets/day19.rs\"\ntest = false\ndoc = false\n"
+        );
+        assert_eq!(
+            read("fuzz/fuzz_targets/day19.rs"),
+            fs::read_to_string(workspace_root().join("fuzz/fuzz_targets/day1.rs"))
+                .unwrap()
+                .replace("day1::Day1", "day19::Day19")
+        );
+
+        assert!(read("day19/Cargo.toml").contains("name = \"day19\""));
+        assert!(read("day19/src/lib.rs").contains("impl Solution for Day19"));
+        assert!(!read("day19/src/main.rs").contains("{{"));
+        assert!(root.join("day19/example.txt").exists());
+        assert!(root.join("day19/answers.txt").exists());
+
+        assert!(new_day(root, 19).is_err());
+    }
+
+    #[test]
+    fn test_template_passes_clippy() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+
+        let libraries = [
+            "aoc-core",
+            "aoc-generate",
+            "aoc-geometry",
+            "aoc-grid",
+            "aoc-parse",
+        ];
+        for library in libraries {
+            copy_dir(&workspace_root().join(library), &root.join(library));
+        }
+        fs::copy(workspace_root().join("Cargo.lock"), root.join("Cargo.lock")).unwrap();
+        let members: String = libraries
+            .iter()
+            .map(|member| format!("\"{member}\", "))
+            .collect();
+        fs::write(
+            root.join("Cargo.toml"),
+            format!("[workspace]\nresolver = \"2\"\nmembers = [{members}\"day19\"]\n"),
+        )
+        .unwrap();
+
+        copy_template(&workspace_root().join("template"), &root.join("day19"), 19).unwrap();
+
+        // Reuse the same target directory across runs, so that only the new day gets checked.
+        let output = std::process::Command::new(env!("CARGO"))
+            .args([
+                "clippy",
+                "--offline",
+                "--quiet",
+                "-p",
+                "day19",
+                "--all-targets",
+            ])
+            .args(["--", "-D", "warnings"])
+            .env("CARGO_TARGET_DIR", workspace_root().join("target/scaffold"))
+            .current_dir(root)
+            .output()
+            .unwrap();
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn copy_dir(from: &Path, to: &Path) {
+        fs::create_dir_all(to).unwrap();
+        for entry in fs::read_dir(from).unwrap() {
+            let path = entry.unwrap().path();
+            let target = to.join(path.file_name().unwrap());
+            if path.is_dir() {
+                copy_dir(&path, &target);
+            } else {
+                fs::copy(&path, &target).unwrap();
+            }
+        }
+    }
+}
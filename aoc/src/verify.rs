@@ -0,0 +1,125 @@
+use std::{fmt::Display, fs};
+
+use aoc_core::{parse_expected_answers, read_input, ExpectedAnswer};
+
+use crate::days::Day;
+
+/// Result of checking a single recorded answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong { expected: String, actual: String },
+    Failed(String),
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub input: String,
+    pub part: u8,
+    pub outcome: Outcome,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} part {}: ", self.input, self.part)?;
+        match &self.outcome {
+            Outcome::Correct => write!(f, "ok"),
+            Outcome::Wrong { expected, actual }
+                if expected.contains('\n') || actual.contains('\n') =>
+            {
+                write!(f, "expected\n{expected}\ngot\n{actual}")
+            }
+            Outcome::Wrong { expected, actual } => write!(f, "expected {expected}, got {actual}"),
+            Outcome::Failed(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+/// Reads the answers recorded for `day` in `dayN/answers.txt`.
+pub fn expected_answers(day: &Day) -> Result<Vec<ExpectedAnswer>, String> {
+    let path = day.file_path("answers");
+    let text = fs::read_to_string(&path)
+        .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+
+    parse_expected_answers(&text)
+        .map_err(|err| format!("Failed to parse {}: {err}", path.display()))
+}
+
+/// Solves every input mentioned in `expected` and compares the results with the recorded answers.
+pub fn verify(day: &Day, expected: &[ExpectedAnswer]) -> Vec<Check> {
+    let mut inputs: Vec<&str> = Vec::new();
+    for e in expected {
+        if !inputs.contains(&e.input.as_str()) {
+            inputs.push(&e.input);
+        }
+    }
+
+    let mut checks = Vec::with_capacity(expected.len());
+    for input in inputs {
+        let answers: Vec<&ExpectedAnswer> = expected.iter().filter(|e| e.input == input).collect();
+        let parts: Vec<u8> = answers.iter().map(|e| e.part).collect();
+
+        let path = day.file_path(input);
+        let results = read_input(&path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))
+            .and_then(|contents| {
+                (day.solve)(&contents, &parts).map_err(|err| format!("failed to parse: {err}"))
+            });
+
+        for (i, expected) in answers.into_iter().enumerate() {
+            let outcome = match &results {
+                Ok(results) => match &results.answers[i].1 {
+                    Ok(answer) => {
+                        let actual = answer.to_string().trim_end().to_string();
+                        if actual == expected.answer {
+                            Outcome::Correct
+                        } else {
+                            Outcome::Wrong {
+                                expected: expected.answer.clone(),
+                                actual,
+                            }
+                        }
+                    }
+                    Err(err) => Outcome::Failed(format!("no answer: {err}")),
+                },
+                Err(err) => Outcome::Failed(err.clone()),
+            };
+
+            checks.push(Check {
+                input: input.to_string(),
+                part: expected.part,
+                outcome,
+            });
+        }
+    }
+
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::DAYS;
+
+    use super::*;
+
+    /// Checks the example answers of every day, which are quick enough to run on each test run.
+    #[test]
+    fn test_examples() {
+        for day in DAYS {
+            let expected: Vec<ExpectedAnswer> = expected_answers(day)
+                .unwrap()
+                .into_iter()
+                .filter(|e| e.input != "input")
+                .collect();
+
+            for check in verify(day, &expected) {
+                assert_eq!(
+                    check.outcome,
+                    Outcome::Correct,
+                    "day {} {check}",
+                    day.number
+                );
+            }
+        }
+    }
+}
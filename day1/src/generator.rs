@@ -0,0 +1,18 @@
+use aoc_generate::{rand::RngExt, Generated, InputRng};
+
+use crate::Day1;
+
+impl Generated for Day1 {
+    /// `size` elves, each carrying up to 15 snacks.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        (0..size.max(3))
+            .map(|_| {
+                let snacks = rng.random_range(1..=15);
+                (0..snacks)
+                    .map(|_| format!("{}\n", rng.random_range(1000..=60000)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
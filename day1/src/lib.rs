@@ -0,0 +1,207 @@
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    collections::BinaryHeap,
+    error::Error,
+    fmt::Display,
+    io::{self, BufRead},
+};
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+
+mod generator;
+/// Summary statistics over the elves' inventories, as text or JSON.
+pub mod report;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<Elf>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input)?.into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input)?.into())
+    }
+}
+
+/// Snacks carried by a single elf, along with their total calories.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Elf {
+    snacks: Vec<i32>,
+    calories: i32,
+}
+
+impl Elf {
+    /// Elf carrying `snacks`, unless their total calories are out of range.
+    pub fn new(snacks: Vec<i32>) -> Option<Self> {
+        let calories = snacks.iter().try_fold(0i32, |total, &cals| total.checked_add(cals))?;
+        Some(Self { snacks, calories })
+    }
+
+    /// Calories of each snack, in the order they were listed.
+    pub fn snacks(&self) -> &[i32] {
+        &self.snacks
+    }
+
+    /// Total calories of all snacks.
+    pub fn calories(&self) -> i32 {
+        self.calories
+    }
+
+    fn add_snack(&mut self, cals: i32) -> Option<()> {
+        self.calories = self.calories.checked_add(cals)?;
+        self.snacks.push(cals);
+        Some(())
+    }
+}
+
+/// Error produced when reading elves from a reader.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::Io(err) => write!(f, "failed to read puzzle input: {err}"),
+            ReadError::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for ReadError {}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+/// Elves read one at a time from puzzle input, so that it never has to be in memory all at once.
+pub struct Elves<R> {
+    lines: io::Lines<R>,
+    line: usize,
+}
+
+impl<R: BufRead> Elves<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            line: 0,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut elf: Option<Elf> = None;
+        for line in self.lines.by_ref() {
+            self.line += 1;
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => return Some(Err(err.into())),
+            };
+
+            // Any number of blank lines separate elves.
+            if line.is_empty() {
+                if elf.is_some() {
+                    break;
+                }
+                continue;
+            }
+
+            let Ok(cals) = line.parse::<i32>() else {
+                return Some(Err(line_error(self.line, &line, "calorie count")));
+            };
+            if elf.get_or_insert_with(Elf::default).add_snack(cals).is_none() {
+                return Some(Err(line_error(self.line, &line, "calorie count that keeps the elf's total in range")));
+            }
+        }
+
+        elf.map(Ok)
+    }
+}
+
+fn line_error(number: usize, line: &str, expected: &str) -> ReadError {
+    ReadError::Parse(ParseError {
+        line: number,
+        ..ParseError::at_offset(line, 0, expected)
+    })
+}
+
+/// Returns the snacks carried by each elf.
+pub fn parse_input(input: &str) -> Result<Vec<Elf>, ParseError> {
+    Elves::new(input.as_bytes())
+        .map(|elf| {
+            elf.map_err(|err| match err {
+                ReadError::Parse(err) => err,
+                // Lines of a string are always valid UTF-8, so reading them cannot fail.
+                ReadError::Io(err) => ParseError::at_offset(input, 0, err.to_string()),
+            })
+        })
+        .collect()
+}
+
+/// Elf ordered by calories alone, with the fewest first, so a heap of them can drop the smallest.
+struct ByCalories<E>(E);
+
+impl<E: Borrow<Elf>> Ord for ByCalories<E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.borrow().calories().cmp(&self.0.borrow().calories())
+    }
+}
+
+impl<E: Borrow<Elf>> PartialOrd for ByCalories<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<E: Borrow<Elf>> PartialEq for ByCalories<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<E: Borrow<Elf>> Eq for ByCalories<E> {}
+
+/// The `k` elves carrying the most calories, most first, or all elves if there are fewer.
+///
+/// Only the best `k` elves seen so far are kept, so `elves` can be streamed.
+pub fn top_k<E: Borrow<Elf>>(elves: impl IntoIterator<Item = E>, k: usize) -> Vec<E> {
+    let mut heap = BinaryHeap::new();
+    for elf in elves {
+        heap.push(ByCalories(elf));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+
+    heap.into_sorted_vec().into_iter().map(|ByCalories(elf)| elf).collect()
+}
+
+pub fn solve_part_1(elves: &[Elf]) -> Result<i32, SolveError> {
+    top_k(elves, 1)
+        .first()
+        .map(|elf| elf.calories())
+        .ok_or_else(|| SolveError::new("No elves in puzzle input"))
+}
+
+pub fn solve_part_2(elves: &[Elf]) -> Result<i64, SolveError> {
+    let top = top_k(elves, 3);
+    if top.len() < 3 {
+        return Err(SolveError::new(format!("Only {} elves in puzzle input, need at least 3", top.len())));
+    }
+    Ok(top.iter().map(|elf| i64::from(elf.calories())).sum())
+}
@@ -1,29 +1,215 @@
-use std::{fs, error};
+use std::{error, process};
+
+use aoc_core::{init_logging, read_input_from_args, Solution};
+use day1::*;
 
 fn main() -> Result<(), Box<dyn error::Error>> {
-    let contents = fs::read_to_string("day1/input.txt")?;
-
-    let mut elf_cals = Vec::new();
-    for (i, elf_cals_str) in contents.split("\r\n\r\n").enumerate() {
-        elf_cals.push(0);
-        for cals_str in elf_cals_str.lines() {
-            let cals: i32 = cals_str.parse()?;
-            elf_cals[i] += cals;
-        }
-    }
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"))?;
+    let input = Day1::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    elf_cals.sort();
-    let most_cals = elf_cals.last();
-    println!("Most calories: {}", most_cals.unwrap());
+    match Day1::part_1(&input) {
+        Ok(answer) => println!("Most calories: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    let n = elf_cals.len();
-    let most_3_cals: i32 = elf_cals[(n-3)..].iter().sum();
-    println!("Most top 3 calories: {}", most_3_cals);
+    match Day1::part_2(&input) {
+        Ok(answer) => println!("Most top 3 calories: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    use std::io;
+
+    use aoc_core::ParseError;
+
+    use super::*;
+
+    const INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn test_part_1() {
+        let sol = solve_part_1(&parse_input(INPUT).unwrap());
+
+        assert_eq!(sol, Ok(24000));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let sol = solve_part_2(&parse_input(INPUT).unwrap());
+
+        assert_eq!(sol, Ok(45000));
+    }
+
+    #[test]
+    fn test_no_answer() {
+        assert!(solve_part_1(&parse_input("").unwrap()).is_err());
+        assert!(solve_part_2(&parse_input("1\n\n2").unwrap()).is_err());
+        assert!(parse_input("2147483647\n1").is_err());
+    }
+
+    #[test]
+    fn test_top_k() {
+        let elves = parse_input(INPUT).unwrap();
+        let calories = |k| top_k(&elves, k).into_iter().map(Elf::calories).collect::<Vec<_>>();
+
+        assert_eq!(calories(0), [] as [i32; 0]);
+        assert_eq!(calories(2), [24000, 11000]);
+        assert_eq!(calories(10), [24000, 11000, 10000, 6000, 4000]);
+        assert_eq!(top_k(elves.clone(), 1), [Elf::new(vec![7000, 8000, 9000]).unwrap()]);
+    }
+
+    #[test]
+    fn test_few_elves() {
+        assert_eq!(solve_part_1(&parse_input("5\n\n\n\n7").unwrap()), Ok(7));
+        assert_eq!(solve_part_2(&parse_input("1\n\n2\n\n3").unwrap()), Ok(6));
+        assert_eq!(solve_part_2(&parse_input("4\n\n4\n\n4\n\n4").unwrap()), Ok(12));
+    }
+
+    #[test]
+    fn test_elf() {
+        let elf = Elf::new(vec![1000, 2000]).unwrap();
+
+        assert_eq!(elf.snacks(), [1000, 2000]);
+        assert_eq!(elf.calories(), 3000);
+        assert_eq!(Elf::new(vec![i32::MAX, 1]), None);
+    }
+
+    #[test]
+    fn test_streaming() {
+        let elves = Elves::new(io::BufReader::with_capacity(4, INPUT.as_bytes()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(elves, parse_input(INPUT).unwrap());
+
+        let elves = Elves::new("\n\n1\n2\n\n\n\n3\n\n".as_bytes())
+            .map(|elf| elf.unwrap().calories())
+            .collect::<Vec<_>>();
+        assert_eq!(elves, [3, 3]);
 
+        let mut elves = Elves::new("1\n\nfour\n".as_bytes());
+        assert!(elves.next().unwrap().is_ok());
+        assert!(matches!(elves.next(), Some(Err(ReadError::Parse(ParseError { line: 3, .. })))));
+
+        let mut elves = Elves::new(&b"1\n\xff\n"[..]);
+        assert!(matches!(elves.next(), Some(Err(ReadError::Io(_)))));
+    }
+
+    #[test]
+    fn test_report() {
+        let report = report::Report::new(&parse_input(INPUT).unwrap(), &report::Options::default()).unwrap();
+
+        assert_eq!((report.elves, report.snacks), (5, 10));
+        assert_eq!((report.min_calories, report.max_calories), (4000, 24000));
+        assert_eq!((report.mean_calories, report.median_calories), (11000.0, 10000.0));
+
+        let percentiles: Vec<_> = report.percentiles.iter().map(|p| (p.percent, p.calories)).collect();
+        assert_eq!(percentiles, [(25, 6000), (50, 10000), (75, 11000), (90, 24000), (99, 24000)]);
+
+        let counts: Vec<_> = report.histogram.iter().map(|bin| bin.count).collect();
+        assert_eq!(counts, [2, 0, 1, 1, 0, 0, 0, 0, 0, 1]);
+        assert_eq!((report.histogram[0].start, report.histogram[0].end), (4000, 6000));
+
+        let ranking: Vec<_> = report.ranking.iter().map(|r| (r.rank, r.elf, r.snacks)).collect();
+        assert_eq!(ranking, [(1, 4, 3), (2, 3, 2), (3, 5, 1), (4, 1, 3), (5, 2, 1)]);
+    }
+
+    #[test]
+    fn test_report_ties() {
+        let options = report::Options { percentiles: vec![0, 100], bins: 3 };
+        let report = report::Report::new(&parse_input("5\n\n7\n\n3\n2\n\n7\n\n1").unwrap(), &options).unwrap();
+
+        let ranking: Vec<_> = report.ranking.iter().map(|r| (r.rank, r.elf)).collect();
+        assert_eq!(ranking, [(1, 2), (1, 4), (3, 1), (3, 3), (5, 5)]);
+        assert_eq!(report.median_calories, 5.0);
+
+        let percentiles: Vec<_> = report.percentiles.iter().map(|p| p.calories).collect();
+        assert_eq!(percentiles, [1, 7]);
+
+        let bins: Vec<_> = report.histogram.iter().map(|bin| (bin.start, bin.end, bin.count)).collect();
+        assert_eq!(bins, [(1, 3, 1), (4, 6, 2), (7, 7, 2)]);
+
+        let single = report::Report::new(&[Elf::new(vec![10]).unwrap()], &options).unwrap();
+        assert_eq!(single.histogram.len(), 1);
+    }
+
+    #[test]
+    fn test_report_errors() {
+        let elves = parse_input(INPUT).unwrap();
+
+        assert!(report::Report::new(&[], &report::Options::default()).is_err());
+        assert!(report::Report::new(&elves, &report::Options { bins: 0, ..report::Options::default() }).is_err());
+        assert!(report::Report::new(&elves, &report::Options { percentiles: vec![101], bins: 1 }).is_err());
+    }
+
+    #[test]
+    fn test_report_output() {
+        let report = report::Report::new(&parse_input(INPUT).unwrap(), &report::Options { percentiles: vec![50], bins: 2 }).unwrap();
+
+        assert_eq!(
+            report.to_string(),
+            "Elves: 5
+Snacks: 10 (2.0 per elf)
+Calories: min 4000, max 24000, mean 11000.0, median 10000.0
+Percentiles: p50 10000
+
+Histogram:
+ 4000..=14000 ######################################## 4
+14001..=24000 ##########                               1
+
+Ranking:
+1. elf 4 24000 calories in 3 snacks
+2. elf 3 11000 calories in 2 snacks
+3. elf 5 10000 calories in 1 snacks
+4. elf 1 6000 calories in 3 snacks
+5. elf 2 4000 calories in 1 snacks
+"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["elves"], 5);
+        assert_eq!(json["median_calories"], 10000.0);
+        assert_eq!(json["histogram"][1]["count"], 1);
+        assert_eq!(json["ranking"][0]["elf"], 4);
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = INPUT.replace('\n', "\r\n");
+
+        assert_eq!(parse_input(&input).unwrap(), parse_input(INPUT).unwrap());
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let elves = parse_input(&aoc_generate::generate::<Day1>(seed, 20)).unwrap();
+
+            assert_eq!(elves.len(), 20);
+            assert!(solve_part_2(&elves).unwrap() > solve_part_1(&elves).unwrap().into());
+        }
+    }
 }
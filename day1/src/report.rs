@@ -0,0 +1,207 @@
+use std::fmt::Display;
+
+use aoc_core::SolveError;
+use serde::Serialize;
+
+use crate::Elf;
+
+/// Settings of a report, which default to quartiles and a few high percentiles over ten bins.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    /// Percentiles of total calories to report, each at most 100.
+    pub percentiles: Vec<u8>,
+    /// Most bins to split the range of total calories into for the histogram.
+    pub bins: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            percentiles: vec![25, 50, 75, 90, 99],
+            bins: 10,
+        }
+    }
+}
+
+/// Summary statistics over the inventories of all elves.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    pub elves: usize,
+    /// Snacks carried by all elves together.
+    pub snacks: usize,
+    pub mean_snacks: f64,
+    pub min_calories: i32,
+    pub max_calories: i32,
+    pub mean_calories: f64,
+    pub median_calories: f64,
+    pub percentiles: Vec<Percentile>,
+    pub histogram: Vec<Bin>,
+    /// Every elf, the most calories first.
+    pub ranking: Vec<Ranked>,
+}
+
+/// Total calories that `percent` percent of elves carry at most, by the nearest rank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Percentile {
+    pub percent: u8,
+    pub calories: i32,
+}
+
+/// Number of elves whose total calories are between `start` and `end`, inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Bin {
+    pub start: i64,
+    pub end: i64,
+    pub count: usize,
+}
+
+/// Place of a single elf in the ranking, with elves carrying the same calories sharing a rank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Ranked {
+    pub rank: usize,
+    /// Position of the elf in the puzzle input, starting at 1.
+    pub elf: usize,
+    pub calories: i32,
+    pub snacks: usize,
+}
+
+impl Report {
+    pub fn new(elves: &[Elf], options: &Options) -> Result<Self, SolveError> {
+        if elves.is_empty() {
+            return Err(SolveError::new("No elves in puzzle input"));
+        }
+        if let Some(percent) = options.percentiles.iter().find(|&&percent| percent > 100) {
+            return Err(SolveError::new(format!("Percentile {percent} is above 100")));
+        }
+        if options.bins == 0 {
+            return Err(SolveError::new("Histogram needs at least one bin"));
+        }
+
+        let mut calories: Vec<i32> = elves.iter().map(Elf::calories).collect();
+        calories.sort_unstable();
+
+        let n = elves.len();
+        let snacks = elves.iter().map(|elf| elf.snacks().len()).sum();
+        let median_calories = if n.is_multiple_of(2) {
+            (f64::from(calories[n / 2 - 1]) + f64::from(calories[n / 2])) / 2.0
+        } else {
+            f64::from(calories[n / 2])
+        };
+
+        let percentiles = options
+            .percentiles
+            .iter()
+            .map(|&percent| {
+                let rank = (usize::from(percent) * n).div_ceil(100).max(1);
+                Percentile { percent, calories: calories[rank - 1] }
+            })
+            .collect();
+
+        Ok(Self {
+            elves: n,
+            snacks,
+            mean_snacks: snacks as f64 / n as f64,
+            min_calories: calories[0],
+            max_calories: calories[n - 1],
+            mean_calories: calories.iter().map(|&cals| f64::from(cals)).sum::<f64>() / n as f64,
+            median_calories,
+            percentiles,
+            histogram: histogram(&calories, options.bins),
+            ranking: ranking(elves),
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Reports are always serializable")
+    }
+}
+
+/// Splits the range of sorted `calories` into at most `bins` bins of equal width, except for the
+/// last one, which ends at the most calories.
+fn histogram(calories: &[i32], bins: usize) -> Vec<Bin> {
+    let min = i64::from(calories[0]);
+    let max = i64::from(calories[calories.len() - 1]);
+    let width = ((max - min) as u64 + 1).div_ceil(bins as u64) as i64;
+
+    let mut histogram: Vec<Bin> = (0..=(max - min) / width)
+        .map(|i| {
+            let start = min + i * width;
+            Bin { start, end: (start + width - 1).min(max), count: 0 }
+        })
+        .collect();
+    for &cals in calories {
+        histogram[((i64::from(cals) - min) / width) as usize].count += 1;
+    }
+
+    histogram
+}
+
+fn ranking(elves: &[Elf]) -> Vec<Ranked> {
+    let mut order: Vec<usize> = (0..elves.len()).collect();
+    order.sort_by_key(|&i| std::cmp::Reverse(elves[i].calories()));
+
+    let mut ranking: Vec<Ranked> = Vec::with_capacity(elves.len());
+    for (place, i) in order.into_iter().enumerate() {
+        let calories = elves[i].calories();
+        let rank = match ranking.last() {
+            Some(prev) if prev.calories == calories => prev.rank,
+            _ => place + 1,
+        };
+        ranking.push(Ranked { rank, elf: i + 1, calories, snacks: elves[i].snacks().len() });
+    }
+
+    ranking
+}
+
+/// Width of the longest bar of the histogram, in characters.
+const BAR_WIDTH: usize = 40;
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Elves: {}", self.elves)?;
+        writeln!(f, "Snacks: {} ({:.1} per elf)", self.snacks, self.mean_snacks)?;
+        writeln!(
+            f,
+            "Calories: min {}, max {}, mean {:.1}, median {:.1}",
+            self.min_calories, self.max_calories, self.mean_calories, self.median_calories
+        )?;
+
+        if !self.percentiles.is_empty() {
+            let percentiles: Vec<_> = self
+                .percentiles
+                .iter()
+                .map(|p| format!("p{} {}", p.percent, p.calories))
+                .collect();
+            writeln!(f, "Percentiles: {}", percentiles.join(", "))?;
+        }
+
+        writeln!(f, "\nHistogram:")?;
+        let most = self.histogram.iter().map(|bin| bin.count).max().unwrap_or(0).max(1);
+        let range_width = self
+            .histogram
+            .iter()
+            .map(|bin| bin.start.to_string().len().max(bin.end.to_string().len()))
+            .max()
+            .unwrap_or(0);
+        for bin in &self.histogram {
+            let bar = "#".repeat((bin.count * BAR_WIDTH).div_ceil(most));
+            writeln!(
+                f,
+                "{:>range_width$}..={:>range_width$} {bar:<BAR_WIDTH$} {}",
+                bin.start, bin.end, bin.count
+            )?;
+        }
+
+        writeln!(f, "\nRanking:")?;
+        let rank_width = self.elves.to_string().len();
+        for ranked in &self.ranking {
+            writeln!(
+                f,
+                "{:>rank_width$}. elf {:<rank_width$} {} calories in {} snacks",
+                ranked.rank, ranked.elf, ranked.calories, ranked.snacks
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use aoc_generate::{rand::RngExt, Generated, InputRng};
+
+use crate::Day10;
+
+/// Cycles it takes the CRT to draw the whole screen, which no program may run longer than.
+const SCREEN_CYCLES: usize = 240;
+
+impl Generated for Day10 {
+    /// Program of `size` instructions, cut short where it would run past the last pixel.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        let mut program = String::new();
+        let mut cycles = 0;
+
+        for _ in 0..size.max(1) {
+            if cycles + 2 <= SCREEN_CYCLES && rng.random_bool(0.6) {
+                program += &format!("addx {}\n", rng.random_range(-10..=10));
+                cycles += 2;
+            } else if cycles < SCREEN_CYCLES {
+                program += "noop\n";
+                cycles += 1;
+            } else {
+                break;
+            }
+        }
+
+        program
+    }
+}
@@ -1,14 +1,89 @@
-use std::str::FromStr;
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_grid::Grid;
+use aoc_visual::{Animated, Frames, Image, NoFrames, Pictured};
 
+mod generator;
 
-pub fn solve_part_1(input: &str) -> i64 {
-    let instructions = input
+pub struct Day10;
+
+/// Puzzle parameters, which default to the ones of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// Cycles during which part 1 samples the signal strength.
+    pub signal_cycles: Vec<usize>,
+    pub screen_width: usize,
+    pub screen_height: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            signal_cycles: (20..=220).step_by(40).collect(),
+            screen_width: 40,
+            screen_height: 6,
+        }
+    }
+}
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input, &Params::default()).into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input, &Params::default())?.into())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
         .lines()
-        .map(|line| line.parse().expect("Failed to parse instruction"))
-        .collect();
+        .map(|line| match line.split_once(' ') {
+            Some(("addx", val_str)) => val_str
+                .parse::<i32>()
+                .map(|val| Instruction::Addx(val.into()))
+                .map_err(|_| ParseError::new(input, val_str, "value for addx instruction")),
+            None if line == "noop" => Ok(Instruction::Noop),
+            _ => Err(ParseError::new(input, line, "instruction (`addx <value>` or `noop`)")),
+        })
+        .collect()
+}
+
+impl Animated for Day10 {
+    fn animate(
+        input: &Self::Input<'_>,
+        part: u8,
+        frames: &mut impl Frames,
+    ) -> Result<Answer, SolveError> {
+        Ok(match part {
+            1 => solve_part_1(input, &Params::default()).into(),
+            2 => animate_part_2(input, &Params::default(), frames)?.into(),
+            _ => return Err(SolveError::new(format!("No part {part} to animate"))),
+        })
+    }
+}
+
+impl Pictured for Day10 {
+    /// The CRT after drawing the whole image.
+    fn image(input: &Self::Input<'_>) -> Image {
+        let screen = solve_part_2(input, &Params::default()).expect("Default screen size is valid");
+        Image::from_text(&screen, 8, |pixel| match pixel {
+            '#' => [120, 255, 120],
+            _ => [10, 20, 10],
+        })
+    }
+}
+
+pub fn solve_part_1(instructions: &[Instruction], params: &Params) -> i64 {
     let mut cpu = Cpu::new(instructions);
 
-    let interesting_cycles: Vec<usize> = (20..=220).step_by(40).collect();
+    let interesting_cycles = &params.signal_cycles;
     let mut result = Vec::with_capacity(interesting_cycles.len());
 
     let mut program_counter = 0usize;
@@ -38,26 +113,36 @@ pub fn solve_part_1(input: &str) -> i64 {
     result.into_iter().sum()
 }
 
-pub fn solve_part_2(input: &str) -> String {
-    let instructions = input
-        .lines()
-        .map(|line| line.parse().expect("Failed to parse instruction"))
-        .collect();
+pub fn solve_part_2(instructions: &[Instruction], params: &Params) -> Result<String, SolveError> {
+    animate_part_2(instructions, params, &mut NoFrames)
+}
+
+/// Solves part 2, showing the screen after every cycle as the CRT scans across it.
+pub fn animate_part_2(instructions: &[Instruction], params: &Params, frames: &mut impl Frames) -> Result<String, SolveError> {
+    if params.screen_width == 0 || params.screen_height == 0 {
+        return Err(SolveError::new(format!(
+            "Screen cannot be {}x{} pixels, it needs at least one pixel",
+            params.screen_width, params.screen_height
+        )));
+    }
+
     let mut cpu = Cpu::new(instructions);
 
-    let mut screen = Screen::new(40, 6);
+    let mut screen = Screen::new(params.screen_width, params.screen_height);
+    let screen_size = screen.pixels.width() * screen.pixels.height();
 
     let mut program_counter = 0usize;
     let mut cycle = 0usize;
     let mut cycles_left = 0usize;
-    while program_counter < cpu.instructions.len() {
+    // Whatever runs after the last pixel is drawn doesn't change the screen.
+    while program_counter < cpu.instructions.len() && cycle < screen_size {
         let curr_instr = &cpu.instructions[program_counter];
 
-        let horizontal_pos = (cycle % screen.width) as i64;
-        match horizontal_pos - cpu.reg_x {
-            -1 | 0 | 1 => screen.put_pixel(cycle),
-            _ => ()
+        let horizontal_pos = (cycle % screen.pixels.width()) as i64;
+        if (-1..=1).contains(&(horizontal_pos - cpu.reg_x)) {
+            screen.put_pixel(cycle);
         }
+        frames.frame(|| screen.display());
 
         match (&curr_instr, cycles_left) {
             (Instruction::Addx(_), 0) => { cycles_left = 2; }
@@ -73,41 +158,21 @@ pub fn solve_part_2(input: &str) -> String {
         cycle += 1;
     }
 
-    screen.display()
+    Ok(screen.display())
 }
 
-enum Instruction {
+pub enum Instruction {
     Addx(i64),
     Noop,
 }
 
-struct Cpu {
-    instructions: Vec<Instruction>,
+struct Cpu<'a> {
+    instructions: &'a [Instruction],
     reg_x: i64,
 }
 
-impl FromStr for Instruction {
-    type Err = &'static str;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split(' ');
-        match split.next() {
-            Some("addx") => {
-                let val = split
-                    .next()
-                    .ok_or("No value provided for addx instruction")?
-                    .parse()
-                    .map_err(|_| "Invalid value provided for addx instruction")?;
-                Ok(Instruction::Addx(val))
-            }
-            Some("noop") => Ok(Instruction::Noop),
-            _ => Err("Invalid instruction encountered")
-        }
-    }
-}
-
-impl Cpu {
-    fn new(instructions: Vec<Instruction>) -> Self {
+impl<'a> Cpu<'a> {
+    fn new(instructions: &'a [Instruction]) -> Self {
         Self {
             instructions,
             reg_x: 1,
@@ -115,29 +180,23 @@ impl Cpu {
     }
 }
 
-#[allow(dead_code)]
 struct Screen {
-    pixels: Vec<char>,
-    width: usize,
-    height: usize,
+    pixels: Grid<char>,
 }
 
 impl Screen {
     fn new(width: usize, height: usize) -> Self {
         Self {
-            pixels: vec!['.'; width * height],
-            width,
-            height,
+            pixels: Grid::new(width, height, '.'),
         }
     }
 
     fn display(&self) -> String {
-        self.pixels
-            .chunks(self.width)
-            .fold(String::new(), |out, line| format!("{out}{}\n", line.iter().collect::<String>()))
+        self.pixels.render(|&pixel| pixel)
     }
 
     fn put_pixel(&mut self, pos: usize) {
-        self.pixels[pos] = '#';
+        let width = self.pixels.width();
+        self.pixels[((pos % width) as i64, (pos / width) as i64)] = '#';
     }
 }
@@ -1,17 +1,34 @@
-use std::fs;
+use std::process;
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day10::*;
 
 fn main() {
-    let contents = fs::read_to_string("day10/input.txt").expect("Failed to read puzzle input");
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).expect("Failed to read puzzle input");
+    let input = Day10::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day10::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2:\n{}", solve_part_2(&contents));
+    match Day10::part_2(&input) {
+        Ok(answer) => println!("Part 2:\n{answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_visual::{Animated, Animation, NoFrames};
+
     use super::*;
 
     const INPUT: &str = "addx 15
@@ -163,14 +180,14 @@ noop";
 
     #[test]
     fn test_part_1() {
-        let sol = solve_part_1(INPUT);
+        let sol = solve_part_1(&parse_input(INPUT).unwrap(), &Params::default());
 
         assert_eq!(sol, 13140);
     }
 
     #[test]
     fn test_part_2() {
-        let sol = solve_part_2(INPUT);
+        let sol = solve_part_2(&parse_input(INPUT).unwrap(), &Params::default());
 
         let output = "##..##..##..##..##..##..##..##..##..##..
 ###...###...###...###...###...###...###.
@@ -180,6 +197,60 @@ noop";
 #######.......#######.......#######.....
 ";
 
-        assert_eq!(sol, output);
+        assert_eq!(sol, Ok(output.to_string()));
+    }
+
+    #[test]
+    fn test_animate() {
+        let mut animation = Animation::headless(Vec::new()).every(40);
+        let sol = animate_part_2(&parse_input(INPUT).unwrap(), &Params::default(), &mut animation).unwrap();
+
+        assert_eq!(animation.offered(), 240);
+
+        let frames = String::from_utf8(animation.finish().unwrap()).unwrap();
+        assert_eq!(frames.matches("Frame ").count(), 6);
+        assert!(frames.starts_with("Frame 0\n#.......................................\n"));
+
+        let last_frame = frames.rsplit("Frame ").next().unwrap();
+        assert!(last_frame.starts_with("200\n"));
+        assert!(last_frame.contains(&sol[..41 * 5]));
+
+        assert!(Day10::animate(&parse_input(INPUT).unwrap(), 3, &mut NoFrames).is_err());
+    }
+
+    #[test]
+    fn test_params() {
+        let program = parse_input(INPUT).unwrap();
+        let params = Params {
+            signal_cycles: vec![20],
+            screen_height: 3,
+            ..Params::default()
+        };
+
+        assert_eq!(solve_part_1(&program, &params), 420);
+        assert_eq!(
+            solve_part_2(&program, &params).unwrap(),
+            solve_part_2(&program, &Params::default()).unwrap()[..41 * 3]
+        );
+
+        assert!(solve_part_2(&program, &Params { screen_width: 0, ..Params::default() }).is_err());
+        assert!(solve_part_2(&program, &Params { screen_height: 0, ..Params::default() }).is_err());
+    }
+
+    #[test]
+    fn test_long_program() {
+        let program = parse_input(&"noop\n".repeat(300)).unwrap();
+
+        assert_eq!(solve_part_2(&program, &Params::default()).unwrap().matches('#').count(), 3 * 6);
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let program = parse_input(&aoc_generate::generate::<Day10>(seed, 200)).unwrap();
+
+            solve_part_1(&program, &Params::default());
+            assert_eq!(solve_part_2(&program, &Params::default()).unwrap().lines().count(), 6);
+        }
     }
 }
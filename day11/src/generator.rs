@@ -0,0 +1,152 @@
+use aoc_generate::{
+    rand::{seq::SliceRandom, RngExt},
+    Generated, InputRng,
+};
+
+use crate::Day11;
+
+/// Divisors of the monkeys' tests, distinct primes like in the puzzle so that their product stays
+/// small enough to square.
+const DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+impl Generated for Day11 {
+    /// Up to eight monkeys holding `size` items between them.
+    ///
+    /// Worry levels in part 1 are never relieved modulo the divisors, so operations that make
+    /// them overflow within 20 rounds are toned down until they no longer do.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        let count = rng.random_range(2..=8);
+
+        let mut divisors = DIVISORS;
+        divisors.shuffle(rng);
+
+        let mut monkeys: Vec<Monkey> = (0..count)
+            .map(|id| {
+                let mut other = || (id + rng.random_range(1..count)) % count;
+                let (if_true, if_false) = (other(), other());
+
+                Monkey {
+                    items: vec![rng.random_range(50..=99)],
+                    operation: match rng.random_range(0..10) {
+                        0 => Operation::Square,
+                        1 => Operation::Double,
+                        2..=5 => Operation::Multiply(rng.random_range(2..=19)),
+                        _ => Operation::Add(rng.random_range(1..=8)),
+                    },
+                    divisor: divisors[id],
+                    if_true,
+                    if_false,
+                }
+            })
+            .collect();
+
+        for _ in count..size {
+            let monkey = rng.random_range(0..count);
+            monkeys[monkey].items.push(rng.random_range(50..=99));
+        }
+
+        while overflows(&monkeys) {
+            let squaring = monkeys
+                .iter()
+                .position(|monkey| matches!(monkey.operation, Operation::Square));
+            let multiplying = monkeys
+                .iter()
+                .position(|monkey| !matches!(monkey.operation, Operation::Add(_)));
+
+            match (squaring, multiplying) {
+                (Some(id), _) => {
+                    monkeys[id].operation = Operation::Multiply(rng.random_range(2..=19))
+                }
+                (None, Some(id)) => monkeys[id].operation = Operation::Add(rng.random_range(1..=8)),
+                (None, None) => unreachable!("Additions alone cannot overflow in 20 rounds"),
+            }
+        }
+
+        monkeys
+            .iter()
+            .enumerate()
+            .map(|(id, monkey)| monkey.describe(id))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Double,
+    Square,
+}
+
+impl Operation {
+    fn apply(self, old: u64) -> Option<u64> {
+        match self {
+            Operation::Add(value) => old.checked_add(value),
+            Operation::Multiply(value) => old.checked_mul(value),
+            Operation::Double => old.checked_add(old),
+            Operation::Square => old.checked_mul(old),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    divisor: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+impl Monkey {
+    fn describe(&self, id: usize) -> String {
+        let items: Vec<String> = self.items.iter().map(u64::to_string).collect();
+        let operation = match self.operation {
+            Operation::Add(value) => format!("+ {value}"),
+            Operation::Multiply(value) => format!("* {value}"),
+            Operation::Double => "+ old".to_string(),
+            Operation::Square => "* old".to_string(),
+        };
+
+        format!(
+            "Monkey {id}:
+  Starting items: {}
+  Operation: new = old {operation}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}
+",
+            items.join(", "),
+            self.divisor,
+            self.if_true,
+            self.if_false,
+        )
+    }
+}
+
+/// Plays the 20 rounds of part 1, checking whether any worry level overflows.
+fn overflows(monkeys: &[Monkey]) -> bool {
+    let mut monkeys = monkeys.to_vec();
+
+    for _ in 0..20 {
+        for id in 0..monkeys.len() {
+            for item in std::mem::take(&mut monkeys[id].items) {
+                let monkey = &monkeys[id];
+                let Some(worry) = monkey.operation.apply(item) else {
+                    return true;
+                };
+
+                let worry = worry / 3;
+                let target = if worry % monkey.divisor == 0 {
+                    monkey.if_true
+                } else {
+                    monkey.if_false
+                };
+                monkeys[target].items.push(worry);
+            }
+        }
+    }
+
+    false
+}
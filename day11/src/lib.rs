@@ -1,72 +1,137 @@
-use std::{cmp::Ordering, fmt::Debug};
+use std::{cmp::Ordering, fmt::Debug, sync::Arc};
 
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use tracing::trace;
+
+mod generator;
 pub mod parser;
 
-pub fn solve_part_1(mut monkeys: Vec<Monkey>) -> u64 {
+pub struct Day11;
+
+/// Puzzle parameters, which default to the ones of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    pub part_1_rounds: usize,
+    pub part_2_rounds: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part_1_rounds: 20,
+            part_2_rounds: 10000,
+        }
+    }
+}
+
+impl Solution for Day11 {
+    type Input<'a> = (Worry, Vec<Monkey>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parser::parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(&input.1, &Params::default())?.into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input.0, &input.1, &Params::default())?.into())
+    }
+}
+
+pub fn solve_part_1(monkeys: &[Monkey], params: &Params) -> Result<u64, SolveError> {
+    let mut monkeys = monkeys.to_vec();
     let mut business = vec![0; monkeys.len()];
 
-    for _ in 0..20 {
-        for turn in 0..monkeys.len() {
+    for round in 1..=params.part_1_rounds {
+        for (turn, inspected) in business.iter_mut().enumerate() {
             let (prev_monkeys, next_monkeys) = monkeys.split_at_mut(turn);
             let (curr_monkey, next_monkeys) = next_monkeys.split_at_mut(1);
             let monkey = &mut curr_monkey[0];
 
             for mut item in monkey.items.drain(..) {
-                item.worry = (monkey.operation)(item.worry) / 3;
+                item.worry = (monkey.operation)(item.worry).ok_or_else(|| overflow(turn))? / 3;
 
                 let target = (monkey.test)(&item.worry);
                 match target.cmp(&turn) {
                     Ordering::Less => prev_monkeys[target].items.push(item),
-                    Ordering::Greater => next_monkeys[target - turn - 1].items.push(item),
-                    _ => panic!("Monkey cannot throw item at itself"),
+                    Ordering::Greater => next_monkeys
+                        .get_mut(target - turn - 1)
+                        .ok_or_else(|| SolveError::new(format!("Monkey {turn} throws to monkey {target}, which doesn't exist")))?
+                        .items
+                        .push(item),
+                    _ => return Err(SolveError::new(format!("Monkey {turn} cannot throw an item at itself"))),
                 }
 
-                business[turn] += 1;
+                *inspected += 1;
             }
         }
+
+        trace!(round, ?business, "Items inspected so far");
     }
 
-    business.sort_by(|a, b| b.cmp(a));
-    business[0] * business[1]
+    monkey_business(business)
 }
 
-pub fn solve_part_2((scm, mut monkeys): (Worry, Vec<Monkey>)) -> u64 {
+pub fn solve_part_2(scm: Worry, monkeys: &[Monkey], params: &Params) -> Result<u64, SolveError> {
+    let mut monkeys = monkeys.to_vec();
     let mut business = vec![0; monkeys.len()];
 
-    for _ in 0..10000 {
-        for turn in 0..monkeys.len() {
+    for round in 1..=params.part_2_rounds {
+        for (turn, inspected) in business.iter_mut().enumerate() {
             let (prev_monkeys, next_monkeys) = monkeys.split_at_mut(turn);
             let (curr_monkey, next_monkeys) = next_monkeys.split_at_mut(1);
             let monkey = &mut curr_monkey[0];
 
             for mut item in monkey.items.drain(..) {
-                item.worry = (monkey.operation)(item.worry) % scm;
+                item.worry = (monkey.operation)(item.worry).ok_or_else(|| overflow(turn))? % scm;
 
                 let target = (monkey.test)(&item.worry);
                 match target.cmp(&turn) {
                     Ordering::Less => prev_monkeys[target].items.push(item),
-                    Ordering::Greater => next_monkeys[target - turn - 1].items.push(item),
-                    _ => panic!("Monkey cannot throw item at itself"),
+                    Ordering::Greater => next_monkeys
+                        .get_mut(target - turn - 1)
+                        .ok_or_else(|| SolveError::new(format!("Monkey {turn} throws to monkey {target}, which doesn't exist")))?
+                        .items
+                        .push(item),
+                    _ => return Err(SolveError::new(format!("Monkey {turn} cannot throw an item at itself"))),
                 }
 
-                business[turn] += 1;
+                *inspected += 1;
             }
         }
+
+        trace!(round, ?business, "Items inspected so far");
     }
 
+    monkey_business(business)
+}
+
+/// Product of the two highest numbers of items inspected.
+fn monkey_business(mut business: Vec<u64>) -> Result<u64, SolveError> {
     business.sort_by(|a, b| b.cmp(a));
-    business[0] * business[1]
+    match business[..] {
+        [first, second, ..] => Ok(first * second),
+        _ => Err(SolveError::new("Monkey business needs at least two monkeys")),
+    }
+}
+
+fn overflow(turn: usize) -> SolveError {
+    SolveError::new(format!("Worry level of an item inspected by monkey {turn} overflows"))
 }
 
-type Worry = u64;
-type OperationFn = Box<dyn Fn(Worry) -> Worry>;
-type TestFn = Box<dyn Fn(&Worry) -> usize>;
+pub type Worry = u64;
+/// Returns `None` when the new worry level overflows.
+type OperationFn = Arc<dyn Fn(Worry) -> Option<Worry> + Send + Sync>;
+type TestFn = Arc<dyn Fn(&Worry) -> usize + Send + Sync>;
 
 #[derive(PartialEq, Eq, Clone)]
 struct Item {
     worry: Worry,
 }
 
+#[derive(Clone)]
 pub struct Monkey {
     items: Vec<Item>,
     operation: OperationFn,
@@ -1,15 +1,28 @@
-use std::fs;
+use std::process;
 
-use day11::{*, parser::parse_input};
+use aoc_core::{init_logging, read_input_from_args, Solution};
+use day11::*;
 
 fn main() {
-    let contents = fs::read_to_string("day11/input.txt").expect("Failed to read puzzle input");
-
-    let (_, input) = parse_input(&contents);
-    println!("Part 1: {}", solve_part_1(input));
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).expect("Failed to read puzzle input");
+
+    let input = Day11::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
+    match Day11::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    let input = parse_input(&contents);
-    println!("Part 2: {}", solve_part_2(input));
+    match Day11::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 }
 
 
@@ -50,20 +63,52 @@ Monkey 3:
 
     #[test]
     fn test_part_1() {
-        let (_, input) = parse_input(INPUT);
+        let (_, input) = parse_input(INPUT).unwrap();
 
-        let sol = solve_part_1(input);
+        let sol = solve_part_1(&input, &Params::default());
 
-        assert_eq!(sol, 10605);
+        assert_eq!(sol, Ok(10605));
     }
 
 
     #[test]
     fn test_part_2() {
-        let input = parse_input(INPUT);
+        let (scm, input) = parse_input(INPUT).unwrap();
+
+        let sol = solve_part_2(scm, &input, &Params::default());
+
+        assert_eq!(sol, Ok(2713310158u64));
+    }
+
+    #[test]
+    fn test_rounds() {
+        let (scm, input) = parse_input(INPUT).unwrap();
+        let params = Params { part_2_rounds: 20, ..Params::default() };
+
+        assert_eq!(solve_part_2(scm, &input, &params), Ok(10197));
+    }
 
-        let sol = solve_part_2(input);
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let (scm, monkeys) = parse_input(&aoc_generate::generate::<Day11>(seed, 20)).unwrap();
+
+            solve_part_1(&monkeys, &Params::default()).unwrap();
+            solve_part_2(scm, &monkeys, &Params::default()).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_no_answer() {
+        let (scm, monkeys) = parse_input("Monkey 0:
+  Starting items: 79
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+").unwrap();
 
-        assert_eq!(sol, 2713310158u64);
+        assert!(solve_part_1(&monkeys, &Params::default()).is_err());
+        assert!(solve_part_2(scm, &monkeys, &Params::default()).is_err());
     }
 }
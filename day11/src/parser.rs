@@ -1,32 +1,41 @@
+use aoc_core::{expect_end, ParseError};
+use aoc_parse::{comma_separated, unsigned};
 use nom::{
     bytes::complete::tag,
-    character::complete::{alphanumeric1, anychar, digit1, line_ending},
-    combinator::map,
+    character::complete::{alphanumeric1, anychar, line_ending},
+    combinator::{map_opt, verify},
     multi::separated_list0,
     sequence::{delimited, preceded, separated_pair, terminated, tuple},
-    IResult,
+    Finish, IResult,
 };
 
 use crate::*;
 
-pub fn parse_input(input: &str) -> (Worry, Vec<Monkey>) {
-    let (_, mut result) =
-        separated_list0(line_ending, monkey)(input).expect("Failed to parse input");
+pub fn parse_input(input: &str) -> Result<(Worry, Vec<Monkey>), ParseError> {
+    let (rest, mut result) = separated_list0(line_ending, monkey)(input)
+        .finish()
+        .map_err(|err| ParseError::new(input, err.input, "monkey description"))?;
+
+    expect_end(input, rest, "monkey description starting with `Monkey <id>:`")?;
 
     result.sort_by_key(|(id, _, _)| *id);
     let (divs, monkeys): (Vec<u64>, Vec<Monkey>) = result.into_iter().map(|(_, div, monkey)| (div, monkey)).unzip();
-    (divs.iter().product(), monkeys)
+    let scm = divs
+        .iter()
+        .try_fold(1 as Worry, |scm, &div| scm.checked_mul(div))
+        .ok_or_else(|| ParseError::new(input, &input[input.len()..], "divisors with a product that fits a worry level"))?;
+
+    Ok((scm, monkeys))
 }
 
 fn starting_items(input: &str) -> IResult<&str, Vec<Item>> {
     let (input, items) = preceded(
         tag("  Starting items: "),
-        separated_list0(tag(", "), digit1),
+        comma_separated(unsigned),
     )(input)?;
 
     let items = items
         .into_iter()
-        .map(|item| item.parse().unwrap())
         .map(|worry| Item { worry })
         .collect();
 
@@ -38,7 +47,7 @@ fn operation(input: &str) -> IResult<&str, OperationFn> {
 }
 
 fn operation_fn(input: &str) -> IResult<&str, OperationFn> {
-    map(
+    map_opt(
         preceded(
             tag("new = old "),
             separated_pair(anychar, tag(" "), alphanumeric1),
@@ -47,42 +56,36 @@ fn operation_fn(input: &str) -> IResult<&str, OperationFn> {
     )(input)
 }
 
-fn get_operation_fn((op, val_str): (char, &str)) -> OperationFn {
+fn get_operation_fn((op, val_str): (char, &str)) -> Option<OperationFn> {
     let val: Option<Worry> = val_str.parse().ok();
 
     match (op, val_str, val) {
-        ('+', "old", _) => Box::new(move |old| old + old),
-        ('+', _, Some(v)) => Box::new(move |old| old + v),
-        ('*', "old", _) => Box::new(move |old| old * old),
-        ('*', _, Some(v)) => Box::new(move |old| old * v),
-        _ => {
-            panic!("Invalid operation encountered!")
-        }
+        ('+', "old", _) => Some(Arc::new(move |old: Worry| old.checked_add(old))),
+        ('+', _, Some(v)) => Some(Arc::new(move |old: Worry| old.checked_add(v))),
+        ('*', "old", _) => Some(Arc::new(move |old: Worry| old.checked_mul(old))),
+        ('*', _, Some(v)) => Some(Arc::new(move |old: Worry| old.checked_mul(v))),
+        _ => None,
     }
 }
 
 fn test(input: &str) -> IResult<&str, (Worry, TestFn)> {
-    let (input, (div_str, if_true, if_false)) = tuple((
-        preceded(tag("  Test: divisible by "), digit1),
+    let (input, (div, if_true, if_false)): (_, (Worry, usize, usize)) = tuple((
+        preceded(tag("  Test: divisible by "), verify(unsigned, |&div: &Worry| div > 0)),
         preceded(
             preceded(line_ending, tag("    If true: throw to monkey ")),
-            digit1,
+            unsigned,
         ),
         preceded(
             preceded(line_ending, tag("    If false: throw to monkey ")),
-            digit1,
+            unsigned,
         ),
     ))(input)?;
 
-    let div: Worry = div_str.parse().unwrap();
-    let if_true: usize = if_true.parse().unwrap();
-    let if_false: usize = if_false.parse().unwrap();
-
     Ok((
         input,
         (
             div,
-            Box::new(
+            Arc::new(
                 move |worry| {
                     if worry % div == 0 {
                         if_true
@@ -96,9 +99,7 @@ fn test(input: &str) -> IResult<&str, (Worry, TestFn)> {
 }
 
 fn monkey(input: &str) -> IResult<&str, (usize, Worry, Monkey)> {
-    let (input, id) = delimited(tag("Monkey "), digit1, terminated(tag(":"), line_ending))(input)?;
-
-    let id: usize = id.parse().unwrap();
+    let (input, id) = delimited(tag("Monkey "), unsigned, terminated(tag(":"), line_ending))(input)?;
 
     let (input, items) = terminated(starting_items, line_ending)(input)?;
     let (input, operation) = terminated(operation, line_ending)(input)?;
@@ -147,7 +148,7 @@ mod tests {
         let (rem, op) = operation(input).unwrap();
 
         let new = op(2);
-        assert_eq!(new, 3);
+        assert_eq!(new, Some(3));
         assert_eq!(rem, "");
 
         let input = "  Operation: new = old + old";
@@ -155,7 +156,7 @@ mod tests {
         let (rem, op) = operation(input).unwrap();
 
         let new = op(2);
-        assert_eq!(new, 4);
+        assert_eq!(new, Some(4));
         assert_eq!(rem, "");
     }
 
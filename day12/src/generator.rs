@@ -0,0 +1,30 @@
+use aoc_generate::{rand::RngExt, Generated, InputRng};
+
+use crate::Day12;
+
+impl Generated for Day12 {
+    /// Heightmap `size` squares wide, at least as wide as the 26 heights to climb.
+    ///
+    /// The start and the target are at both ends of a row that climbs steadily from `a` to `z`,
+    /// so there is always a way up, while all other squares have random heights.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        let width = size.max(26);
+        let height = (width / 3).max(5);
+        let path_row = rng.random_range(0..height);
+
+        let mut heightmap = String::new();
+        for y in 0..height {
+            for x in 0..width {
+                heightmap.push(match (x, y) {
+                    (0, y) if y == path_row => 'S',
+                    (x, y) if y == path_row && x == width - 1 => 'E',
+                    (x, y) if y == path_row => char::from(b'a' + (x * 25 / (width - 1)) as u8),
+                    _ => char::from(b'a' + rng.random_range(0..26)),
+                });
+            }
+            heightmap.push('\n');
+        }
+
+        heightmap
+    }
+}
@@ -1,120 +1,119 @@
-use std::collections::VecDeque;
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_grid::{Grid, Position};
+use aoc_search::{bfs_multi, Path};
+use aoc_visual::{Image, Pictured};
 
-pub fn solve_part_1(input: &str) -> i32 {
-    let heightmap = Heightmap::new(input);
+mod generator;
 
-    find_path_to_target(&heightmap, heightmap.start)
-        .expect("Couldn't find path from start to the target")
-}
+pub struct Day12;
 
-pub fn solve_part_2(input: &str) -> i32 {
-    let heightmap = Heightmap::new(input);
+impl Solution for Day12 {
+    type Input<'a> = Heightmap;
 
-    let possible_starts = heightmap
-        .squares
-        .iter()
-        .enumerate()
-        .filter(|(_, &s)| s == 0)
-        .map(|(i, _)| i);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
 
-    let path_lengths = possible_starts.map(|start| find_path_to_target(&heightmap, start));
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input)?.into())
+    }
 
-    path_lengths.into_iter().flatten().min().unwrap()
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input)?.into())
+    }
 }
 
-fn find_path_to_target(heightmap: &Heightmap, start: usize) -> Option<i32> {
-    let mut steps_needed = vec![None; heightmap.squares.len()];
-    steps_needed[start] = Some(0);
-
-    let mut to_consider = VecDeque::from([start]);
-    while let Some(square_idx) = to_consider.pop_front() {
-        let square = heightmap.squares[square_idx];
-        let neighbours = heightmap.get_neighbours(square_idx);
-
-        for n_idx in neighbours {
-            let neigh = heightmap.squares[n_idx];
-            if neigh <= square + 1 {
-                let steps = steps_needed[square_idx].unwrap() + 1;
-                if steps_needed[n_idx].is_none() || steps_needed[n_idx].unwrap() > steps {
-                    steps_needed[n_idx] = Some(steps);
-                    to_consider.push_back(n_idx);
-                }
+impl Pictured for Day12 {
+    /// Heights from blue to white, with the shortest path from the start in red.
+    fn image(input: &Self::Input<'_>) -> Image {
+        let mut image = Image::from_grid(&input.squares, 4, |&height| {
+            let shade = height * 9;
+            [shade, shade, 80 + shade / 2]
+        });
+
+        let path = find_path_to_target(input, [input.start]);
+        for (x, y) in path.into_iter().flat_map(|path| path.nodes) {
+            let (x, y) = (x as usize * 4, y as usize * 4);
+            for (dx, dy) in [(1, 1), (2, 1), (1, 2), (2, 2)] {
+                image.set(x + dx, y + dy, [220, 40, 40]);
             }
         }
 
-        if square_idx == heightmap.target {
-            break;
-        }
+        image
     }
+}
+
+pub fn parse_input(input: &str) -> Result<Heightmap, ParseError> {
+    Heightmap::new(input)
+}
 
-    steps_needed[heightmap.target]
+pub fn solve_part_1(heightmap: &Heightmap) -> Result<usize, SolveError> {
+    find_path_to_target(heightmap, [heightmap.start])
+        .map(|path| path.cost)
+        .ok_or_else(|| SolveError::new("No path from the start to the target"))
+}
+
+pub fn solve_part_2(heightmap: &Heightmap) -> Result<usize, SolveError> {
+    let possible_starts = heightmap
+        .squares
+        .cells()
+        .filter(|(_, &s)| s == 0)
+        .map(|(pos, _)| pos);
+
+    find_path_to_target(heightmap, possible_starts)
+        .map(|path| path.cost)
+        .ok_or_else(|| SolveError::new("No path from any lowest square to the target"))
+}
+
+/// Finds the shortest path to the target from the closest of `starts`.
+fn find_path_to_target(
+    heightmap: &Heightmap,
+    starts: impl IntoIterator<Item = Position>,
+) -> Option<Path<Position, usize>> {
+    let squares = &heightmap.squares;
+    let climbable = |&pos: &Position| {
+        squares
+            .neighbours_4(pos)
+            .filter(move |&next| squares[next] <= squares[pos] + 1)
+    };
+
+    bfs_multi(starts, climbable, |&pos| pos == heightmap.target)
 }
 
 fn read_height_char(ch: char) -> u8 {
     ch as u8 - b'a'
 }
 
-struct Heightmap {
-    squares: Vec<u8>,
-    width: usize,
-    height: usize,
-    start: usize,
-    target: usize,
+pub struct Heightmap {
+    squares: Grid<u8>,
+    start: Position,
+    target: Position,
 }
 
 impl Heightmap {
-    fn new(input: &str) -> Self {
-        let mut start = 0;
-        let mut target = 0;
-
-        let squares = input
-            .lines()
-            .flat_map(|line| line.chars())
-            .enumerate()
-            .map(|(i, ch)| match ch {
-                'S' => {
-                    start = i;
-                    read_height_char('a')
-                }
-                'E' => {
-                    target = i;
-                    read_height_char('z')
-                }
-                _ => read_height_char(ch),
-            })
-            .collect();
-
-        let width = input.lines().next().unwrap().len();
-        let height = input.lines().count();
-
-        Self {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let chars = Grid::parse(input, "square height (a-z, S or E)", |ch| {
+            matches!(ch, 'a'..='z' | 'S' | 'E').then_some(ch)
+        })?;
+
+        let end = &input[input.len()..];
+        let start = chars
+            .position(|&ch| ch == 'S')
+            .ok_or_else(|| ParseError::new(input, end, "start square `S`"))?;
+        let target = chars
+            .position(|&ch| ch == 'E')
+            .ok_or_else(|| ParseError::new(input, end, "target square `E`"))?;
+
+        let squares = chars.map(|&ch| match ch {
+            'S' => read_height_char('a'),
+            'E' => read_height_char('z'),
+            _ => read_height_char(ch),
+        });
+
+        Ok(Self {
             squares,
-            width,
-            height,
             start,
             target,
-        }
-    }
-
-    fn get_neighbours(&self, idx: usize) -> Vec<usize> {
-        let x = idx % self.width;
-        let y = idx / self.width;
-
-        let mut neighbours = Vec::new();
-
-        if y as isize > 0 {
-            neighbours.push(x + (y - 1) * self.width);
-        }
-        if x + 1 < self.width {
-            neighbours.push(x + 1 + y * self.width);
-        }
-        if x as isize > 0 {
-            neighbours.push(x - 1 + y * self.width);
-        }
-        if y + 1 < self.height {
-            neighbours.push(x + (y + 1) * self.width)
-        }
-
-        neighbours
+        })
     }
 }
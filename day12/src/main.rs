@@ -1,17 +1,34 @@
-use std::fs;
+use std::process;
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day12::*;
 
 fn main() {
-    let contents = fs::read_to_string("day12/input.txt").expect("Failed to read puzzle input");
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).expect("Failed to read puzzle input");
+    let input = Day12::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day12::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    match Day12::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_visual::Pictured;
+
     use super::*;
 
     const INPUT: &str = "Sabqponm
@@ -22,14 +39,42 @@ abdefghi";
 
     #[test]
     fn test_part_1() {
-        let sol = solve_part_1(INPUT);
+        let sol = solve_part_1(&parse_input(INPUT).unwrap());
 
-        assert_eq!(sol, 31);
+        assert_eq!(sol, Ok(31));
     }
 
     #[test]
     fn test_part_2() {
-        let sol = solve_part_2(INPUT);
+        let sol = solve_part_2(&parse_input(INPUT).unwrap());
+
+        assert_eq!(sol, Ok(29));}
+
+    #[test]
+    fn test_no_path() {
+        let heightmap = parse_input("SazE").unwrap();
+
+        assert!(solve_part_1(&heightmap).is_err());
+        assert!(solve_part_2(&heightmap).is_err());
+    }
 
-        assert_eq!(sol, 29);}
+    #[test]
+    fn test_image() {
+        let image = Day12::image(&parse_input(INPUT).unwrap());
+
+        assert_eq!((image.width(), image.height()), (32, 20));
+        assert_eq!(image.get(1, 1), Some([220, 40, 40]));
+        assert_ne!(image.get(0, 0), Some([220, 40, 40]));
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let heightmap = parse_input(&aoc_generate::generate::<Day12>(seed, 30)).unwrap();
+
+            let steps = solve_part_1(&heightmap).unwrap();
+            assert!(steps >= 25);
+            assert!(solve_part_2(&heightmap).unwrap() <= steps);
+        }
+    }
 }
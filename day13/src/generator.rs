@@ -0,0 +1,27 @@
+use aoc_generate::{rand::RngExt, Generated, InputRng};
+
+use crate::Day13;
+
+impl Generated for Day13 {
+    /// `size` pairs of packets, nested up to four lists deep.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn packet(rng: &mut InputRng, depth: usize) -> String {
+    let items: Vec<String> = (0..rng.random_range(0..=5))
+        .map(|_| {
+            if depth < 4 && rng.random_bool(0.3) {
+                packet(rng, depth + 1)
+            } else {
+                rng.random_range(0..=10).to_string()
+            }
+        })
+        .collect();
+
+    format!("[{}]", items.join(","))
+}
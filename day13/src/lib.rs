@@ -1,28 +1,43 @@
-mod parser;
+mod generator;
+pub mod parser;
 
 use std::cmp::Ordering;
 
-use parser::parse_input;
+use aoc_core::{Answer, ParseError, Solution, SolveError};
 
-pub fn solve_part_1(input: &str) -> usize {
-    let packet_pairs = parse_input(input);
+pub struct Day13;
 
+impl Solution for Day13 {
+    type Input<'a> = Vec<(Packet, Packet)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parser::parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input).into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input).into())
+    }
+}
+
+pub fn solve_part_1(packet_pairs: &[(Packet, Packet)]) -> usize {
     packet_pairs
-        .into_iter()
+        .iter()
         .enumerate()
         .filter(|(_, (p1, p2))| p1 < p2)
         .map(|(i, _)| i + 1)
         .sum()
 }
 
-pub fn solve_part_2(input: &str) -> usize {
-    let packet_pairs = parse_input(input);
-
+pub fn solve_part_2(packet_pairs: &[(Packet, Packet)]) -> usize {
     let p1 = Packet::new_list(vec![Packet::new_list(vec![Packet::new_int(2)])]);
     let p2 = Packet::new_list(vec![Packet::new_list(vec![Packet::new_int(6)])]);
 
-    let mut packets: Vec<Packet> = packet_pairs.into_iter()
-        .flat_map(|(p1, p2)| [p1, p2])
+    let mut packets: Vec<Packet> = packet_pairs.iter()
+        .flat_map(|(p1, p2)| [p1.clone(), p2.clone()])
         .chain([p1.clone(), p2.clone()])
         .collect();
 
@@ -37,7 +52,7 @@ pub fn solve_part_2(input: &str) -> usize {
 type Int = u32;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum Packet {
+pub enum Packet {
     Integer(Int),
     List(Vec<Packet>),
 }
@@ -54,10 +69,16 @@ impl Packet {
 
 impl PartialOrd for Packet {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
         use Packet::*;
 
         match (self, other) {
-            (Integer(ref x), Integer(ref y)) => x.partial_cmp(y),
+            (Integer(ref x), Integer(ref y)) => x.cmp(y),
             (List(ref packets), List(ref other_packets)) => {
                 let ord = packets
                     .iter()
@@ -70,16 +91,10 @@ impl PartialOrd for Packet {
                         })
                     });
 
-                ord.or_else(|| packets.len().partial_cmp(&other_packets.len()))
+                ord.unwrap_or_else(|| packets.len().cmp(&other_packets.len()))
             }
-            (List(_), Integer(_)) => self.partial_cmp(&Packet::new_list(vec![other.clone()])),
-            (Integer(_), List(_)) => Packet::new_list(vec![self.clone()]).partial_cmp(other),
+            (List(_), Integer(_)) => self.cmp(&Packet::new_list(vec![other.clone()])),
+            (Integer(_), List(_)) => Packet::new_list(vec![self.clone()]).cmp(other),
         }
     }
 }
-
-impl Ord for Packet {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
-    }
-}
@@ -1,13 +1,28 @@
-use std::fs;
+use std::process;
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day13::*;
 
 fn main() {
-    let contents = fs::read_to_string("day13/input.txt").expect("Failed to read puzzle input");
-
-    println!("Part 1: {}", solve_part_1(&contents));
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).expect("Failed to read puzzle input");
+    let input = Day13::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
+
+    match Day13::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    match Day13::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 }
 
 #[cfg(test)]
@@ -40,15 +55,26 @@ mod tests {
 
     #[test]
     fn test_part_1() {
-        let sol = solve_part_1(INPUT);
+        let sol = solve_part_1(&parser::parse_input(INPUT).unwrap());
 
         assert_eq!(sol, 13);
     }
 
     #[test]
     fn test_part_2() {
-        let sol = solve_part_2(INPUT);
+        let sol = solve_part_2(&parser::parse_input(INPUT).unwrap());
 
         assert_eq!(sol, 140);
     }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let pairs = parser::parse_input(&aoc_generate::generate::<Day13>(seed, 20)).unwrap();
+
+            assert_eq!(pairs.len(), 20);
+            assert!(solve_part_1(&pairs) <= 210);
+            assert!(solve_part_2(&pairs) >= 2);
+        }
+    }
 }
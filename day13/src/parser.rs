@@ -1,27 +1,23 @@
+use aoc_core::ParseError;
+use aoc_parse::{blocks, parse_all, unsigned};
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{digit1, line_ending},
-    combinator::{map, map_res},
+    character::complete::line_ending,
+    combinator::map,
     multi::separated_list0,
-    sequence::{delimited, separated_pair, pair},
+    sequence::{delimited, separated_pair},
     IResult,
 };
 
 use crate::*;
 
-pub(crate) fn parse_input(input: &str) -> Vec<(Packet, Packet)> {
-    let (_, packets) = packet_pairs(input).expect("Failed to parse input");
-
-    packets
-}
-
-fn integer(input: &str) -> IResult<&str, Int> {
-    map_res(digit1, str::parse)(input)
+pub fn parse_input(input: &str) -> Result<Vec<(Packet, Packet)>, ParseError> {
+    parse_all(input, packet_pairs, "packet like `[1,[2,3]]`")
 }
 
 fn int_packet(input: &str) -> IResult<&str, Packet> {
-    map(integer, Packet::new_int)(input)
+    map(unsigned::<Int>, Packet::new_int)(input)
 }
 
 fn list(input: &str) -> IResult<&str, Vec<Packet>> {
@@ -41,7 +37,7 @@ fn packet_pair(input: &str) -> IResult<&str, (Packet, Packet)> {
 }
 
 fn packet_pairs(input: &str) -> IResult<&str, Vec<(Packet, Packet)>> {
-    separated_list0(pair(line_ending, line_ending), packet_pair)(input)
+    blocks(packet_pair)(input)
 }
 
 #[cfg(test)]
@@ -0,0 +1,35 @@
+use aoc_generate::{rand::RngExt, Generated, InputRng};
+
+use crate::Day14;
+
+impl Generated for Day14 {
+    /// `size` rock paths of up to five segments each.
+    ///
+    /// Rocks stay within the triangle below the source that sand can reach on the floor, which
+    /// is all the cave that gets simulated.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let mut y: i64 = rng.random_range(13..=160);
+                let mut x: i64 = 500 + rng.random_range(-y / 2..=y / 2);
+                let mut points = vec![format!("{x},{y}")];
+
+                for segment in 0..rng.random_range(1..=5) {
+                    if segment % 2 == 0 {
+                        let step = rng.random_range(1..=8);
+                        let mut next = x + if rng.random_bool(0.5) { step } else { -step };
+                        if (next - 500).abs() > y {
+                            next = x - (next - x);
+                        }
+                        x = next;
+                    } else {
+                        y += rng.random_range(1..=8);
+                    }
+                    points.push(format!("{x},{y}"));
+                }
+
+                points.join(" -> ") + "\n"
+            })
+            .collect()
+    }
+}
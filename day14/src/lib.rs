@@ -1,15 +1,86 @@
-use std::fmt::{Debug, Write};
+use std::fmt::Debug;
 
-mod parser;
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_geometry::Point2;
+use aoc_grid::Grid;
+use aoc_visual::{Animated, Frames, Image, NoFrames, Pictured};
+use tracing::trace;
 
-use parser::parse_input;
+mod generator;
+pub mod parser;
 
-pub fn solve_part_1(input: &str) -> usize {
-    let mut cave = parse_input(input);
-    let sand_spawn = Point::new(500, 0);
+pub struct Day14;
+
+/// Puzzle parameters, which default to the ones of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// Position `(x, y)` the sand pours in from, with `y` growing downwards.
+    pub sand_source: (i64, i64),
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { sand_source: (500, 0) }
+    }
+}
+
+impl Solution for Day14 {
+    type Input<'a> = Cave;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parser::parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input, &Params::default()).into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input, &Params::default()).into())
+    }
+}
+
+impl Animated for Day14 {
+    fn animate(
+        input: &Self::Input<'_>,
+        part: u8,
+        frames: &mut impl Frames,
+    ) -> Result<Answer, SolveError> {
+        Ok(match part {
+            1 => animate_part_1(input, &Params::default(), frames).into(),
+            2 => animate_part_2(input, &Params::default(), frames).into(),
+            _ => return Err(SolveError::new(format!("No part {part} to animate"))),
+        })
+    }
+}
+
+impl Pictured for Day14 {
+    /// The sand pile once it blocks the source, on the floor of part 2.
+    fn image(input: &Self::Input<'_>) -> Image {
+        let chamber = fill_to_source(input, &Params::default(), &mut NoFrames);
+        Image::from_grid(&chamber.tiles, 2, |tile| match tile {
+            Tile::Air => [15, 15, 35],
+            Tile::Rock => [110, 100, 95],
+            Tile::Sand => [230, 180, 80],
+        })
+    }
+}
+
+pub fn solve_part_1(cave: &Cave, params: &Params) -> usize {
+    animate_part_1(cave, params, &mut NoFrames)
+}
+
+pub fn solve_part_2(cave: &Cave, params: &Params) -> usize {
+    animate_part_2(cave, params, &mut NoFrames)
+}
+
+/// Solves part 1, showing the cave each time a unit of sand comes to rest.
+pub fn animate_part_1(cave: &Cave, params: &Params, frames: &mut impl Frames) -> usize {
+    let mut chamber = Chamber::new(cave, params);
+    let sand_spawn = chamber.source;
 
     let mut sand = sand_spawn;
-    while sand.y as usize <= cave.height - 2 {
+    while sand.y < chamber.floor() - 1 {
         let targets = [
             Point::new(sand.x, sand.y + 1),
             Point::new(sand.x - 1, sand.y + 1),
@@ -18,24 +89,34 @@ pub fn solve_part_1(input: &str) -> usize {
 
         if let Some(target_pos) = targets
             .into_iter()
-            .find(|pos| cave.get_tile(pos) == &Tile::Air)
+            .find(|pos| chamber.get_tile(pos) == &Tile::Air)
         {
             sand = target_pos;
         } else {
-            *cave.get_tile_mut(&sand) = Tile::Sand;
+            *chamber.get_tile_mut(&sand) = Tile::Sand;
+            frames.frame(|| chamber.render());
+
+            // Rock wide enough to catch all the sand makes it pile up to the source.
+            if sand == sand_spawn {
+                break;
+            }
             sand = sand_spawn;
         }
     }
 
-    cave.tiles
-        .iter()
-        .filter(|&&tile| tile == Tile::Sand)
-        .count()
+    trace!("Cave once no more sand comes to rest:\n{chamber:?}");
+    chamber.sand_count()
+}
+
+/// Solves part 2, showing the cave each time a unit of sand comes to rest.
+pub fn animate_part_2(cave: &Cave, params: &Params, frames: &mut impl Frames) -> usize {
+    fill_to_source(cave, params, frames).sand_count()
 }
 
-pub fn solve_part_2(input: &str) -> usize {
-    let mut cave = parse_input(input);
-    let sand_spawn = Point::new(500, 0);
+/// Pours sand onto the floor until it piles up to the source.
+fn fill_to_source(cave: &Cave, params: &Params, frames: &mut impl Frames) -> Chamber {
+    let mut chamber = Chamber::new(cave, params);
+    let sand_spawn = chamber.source;
 
     let mut sand = sand_spawn;
     loop {
@@ -47,34 +128,25 @@ pub fn solve_part_2(input: &str) -> usize {
 
         if let Some(target_pos) = targets
             .into_iter()
-            .find(|pos| cave.height > pos.y as usize && cave.get_tile(pos) == &Tile::Air)
+            .find(|&pos| chamber.tiles.get(pos.into()) == Some(&Tile::Air))
         {
             sand = target_pos;
-        } else if sand == sand_spawn {
-            break;
         } else {
-            *cave.get_tile_mut(&sand) = Tile::Sand;
+            *chamber.get_tile_mut(&sand) = Tile::Sand;
+            frames.frame(|| chamber.render());
+
+            if sand == sand_spawn {
+                break;
+            }
             sand = sand_spawn;
         }
     }
 
-    cave.tiles
-        .iter()
-        .filter(|&&tile| tile == Tile::Sand)
-        .count() + 1
+    trace!("Cave once sand blocks the source:\n{chamber:?}");
+    chamber
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-struct Point {
-    x: i32,
-    y: i32,
-}
-
-impl Point {
-    fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
-    }
-}
+type Point = Point2<i64>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
@@ -83,65 +155,77 @@ enum Tile {
     Sand,
 }
 
-struct Cave {
-    tiles: Vec<Tile>,
-    width: usize,
-    height: usize,
-    left: i32,
+/// Rocks found by the scan.
+#[derive(Debug, Clone)]
+pub struct Cave {
+    rocks: Vec<Point>,
 }
 
 impl Cave {
     fn new(rocks: Vec<Point>) -> Self {
-        let bottom = rocks.iter()
-            .fold(0, |bottom, rock| bottom.max(rock.y)) + 2;
-        let left = 500 - bottom;
-        let right = 500 + bottom;
-
-        let width = (right - left - 1) as usize;
-        let height = bottom as usize;
-        let mut tiles = vec![Tile::Air; width * height];
-        for rock in rocks {
-            let x = (rock.x - left - 1) as usize;
-            let y = rock.y as usize;
-            tiles[x + y * width] = Tile::Rock;
-        }
+        Self { rocks }
+    }
+}
 
-        Self {
-            tiles,
-            width,
-            height,
-            left,
+/// The part of the cave that sand poured in at the source can reach before hitting the floor.
+#[derive(Clone)]
+struct Chamber {
+    tiles: Grid<Tile>,
+    source: Point,
+}
+
+impl Chamber {
+    fn new(cave: &Cave, params: &Params) -> Self {
+        let source = Point::new(params.sand_source.0, params.sand_source.1);
+        let bottom = cave.rocks.iter()
+            .fold(source.y, |bottom, rock| bottom.max(rock.y)) + 2;
+        let depth = bottom - source.y;
+
+        let width = (2 * depth - 1) as usize;
+        let height = depth as usize;
+        let mut tiles = Grid::new(width, height, Tile::Air).with_origin((source.x - depth + 1, source.y));
+        // Sand never reaches rocks outside of the pile under the source.
+        for &rock in &cave.rocks {
+            if let Some(tile) = tiles.get_mut(rock.into()) {
+                *tile = Tile::Rock;
+            }
         }
+
+        Self { tiles, source }
+    }
+
+    /// Row of the floor of part 2, just below the tiles.
+    fn floor(&self) -> i64 {
+        self.source.y + self.tiles.height() as i64
+    }
+
+    fn sand_count(&self) -> usize {
+        self.tiles
+            .iter()
+            .filter(|&&tile| tile == Tile::Sand)
+            .count()
+    }
+
+    fn render(&self) -> String {
+        self.tiles.render(|tile| match tile {
+            Tile::Air => '.',
+            Tile::Rock => '#',
+            Tile::Sand => 'o',
+        })
     }
 
     fn get_tile(&self, point: &Point) -> &Tile {
-        let x = (point.x - self.left - 1) as usize;
-        let y = point.y as usize;
-        &self.tiles[x + y * self.width]
+        &self.tiles[(*point).into()]
     }
 
     fn get_tile_mut(&mut self, point: &Point) -> &mut Tile {
-        let x = (point.x - self.left - 1) as usize;
-        let y = point.y as usize;
-        &mut self.tiles[x + y * self.width]
+        &mut self.tiles[(*point).into()]
     }
 }
 
-impl Debug for Cave {
+impl Debug for Chamber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!("{}x{}\n", self.width, self.height))?;
-        for y in 0..self.height {
-            for x in (self.width/5)..=(self.width*4/5) {
-                let ch = match self.tiles[x + y * self.width] {
-                    Tile::Air => '.',
-                    Tile::Rock => '#',
-                    Tile::Sand => 'o',
-                };
-                f.write_char(ch)?;
-            }
-            f.write_char('\n')?;
-        }
-
-        Ok(())
+        writeln!(f, "{}x{}", self.tiles.width(), self.tiles.height())?;
+        f.write_str(&self.render())
     }
 }
@@ -1,17 +1,34 @@
-use std::fs;
+use std::process;
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day14::*;
 
 fn main() {
-    let contents = fs::read_to_string("day14/input.txt").expect("Failed to read puzzle input");
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).expect("Failed to read puzzle input");
+    let input = Day14::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day14::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    match Day14::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_visual::{Animated, Animation, NoFrames, Pictured};
+
     use super::*;
 
     const INPUT: &str = "498,4 -> 498,6 -> 496,6
@@ -19,15 +36,72 @@ mod tests {
 
     #[test]
     fn test_part_1() {
-        let sol = solve_part_1(INPUT);
+        let sol = solve_part_1(&parser::parse_input(INPUT).unwrap(), &Params::default());
 
         assert_eq!(sol, 24);
     }
 
     #[test]
     fn test_part_2() {
-        let sol = solve_part_2(INPUT);
+        let sol = solve_part_2(&parser::parse_input(INPUT).unwrap(), &Params::default());
 
         assert_eq!(sol, 93);
     }
+
+    #[test]
+    fn test_sand_source() {
+        let shifted = "501,6 -> 501,8 -> 499,8\n506,6 -> 505,6 -> 505,11 -> 497,11";
+        let cave = parser::parse_input(shifted).unwrap();
+        let params = Params { sand_source: (503, 2) };
+
+        assert_eq!(solve_part_1(&cave, &params), 24);
+        assert_eq!(solve_part_2(&cave, &params), 93);
+    }
+
+    #[test]
+    fn test_rocks_out_of_reach() {
+        let sol = solve_part_1(&parser::parse_input("0,9 -> 3,9\n499,3 -> 501,3").unwrap(), &Params::default());
+
+        assert_eq!(sol, 1);
+    }
+
+    #[test]
+    fn test_source_blocked() {
+        let sol = solve_part_1(&parser::parse_input("490,2 -> 510,2").unwrap(), &Params::default());
+
+        assert_eq!(sol, 4);
+    }
+
+    #[test]
+    fn test_animate() {
+        let mut animation = Animation::headless(Vec::new());
+        let sol = animate_part_1(&parser::parse_input(INPUT).unwrap(), &Params::default(), &mut animation);
+
+        assert_eq!(sol, 24);
+        assert_eq!(animation.offered(), 24);
+
+        let frames = String::from_utf8(animation.finish().unwrap()).unwrap();
+        let last_frame = frames.split("Frame ").last().unwrap();
+        assert!(last_frame.starts_with("23\n"));
+        assert_eq!(last_frame.matches('o').count(), 24);
+
+        assert!(Day14::animate(&parser::parse_input(INPUT).unwrap(), 3, &mut NoFrames).is_err());
+    }
+
+    #[test]
+    fn test_image() {
+        let image = Day14::image(&parser::parse_input(INPUT).unwrap());
+
+        assert_eq!((image.width(), image.height()), (42, 22));
+        assert_eq!(image.get(20, 0), Some([230, 180, 80]));
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let cave = parser::parse_input(&aoc_generate::generate::<Day14>(seed, 20)).unwrap();
+
+            assert!(solve_part_1(&cave, &Params::default()) < solve_part_2(&cave, &Params::default()));
+        }
+    }
 }
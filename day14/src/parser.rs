@@ -1,30 +1,35 @@
 use std::collections::HashSet;
 
+use aoc_core::ParseError;
+use aoc_parse::{lines, pair_of, parse_all, unsigned};
 use nom::{
     bytes::complete::tag,
-    character::complete::{digit1, multispace0, line_ending},
-    combinator::{map, map_res},
-    sequence::{separated_pair, delimited},
-    IResult, multi::separated_list1,
+    character::complete::multispace0,
+    combinator::{map, verify},
+    multi::separated_list1,
+    sequence::delimited,
+    IResult,
 };
 
 use crate::*;
 
-pub(crate) fn parse_input(input: &str) -> Cave {
-    let (_, rock_paths) = path_list(input).expect("Failed to parse puzzle input");
+pub fn parse_input(input: &str) -> Result<Cave, ParseError> {
+    let rock_paths = parse_all(input, path_list, "rock path like `498,4 -> 498,6`")?;
 
-    let rocks: HashSet<Point> = rock_paths
-        .into_iter()
-        .flat_map(create_path)
-        .collect();
+    let mut rocks: HashSet<Point> = HashSet::new();
+    for (path_points, line) in rock_paths.into_iter().zip(input.lines()) {
+        let path = create_path(path_points)
+            .ok_or_else(|| ParseError::new(input, line, "only horizontal or vertical path segments"))?;
+        rocks.extend(path);
+    }
 
-    Cave::new(rocks.into_iter().collect())
+    Ok(Cave::new(rocks.into_iter().collect()))
 }
 
-fn create_path(path_points: Vec<Point>) -> Vec<Point> {
+fn create_path(path_points: Vec<Point>) -> Option<Vec<Point>> {
     path_points
         .windows(2)
-        .flat_map(|points| {
+        .map(|points| {
             let start = points[0];
             let end = points[1];
 
@@ -32,32 +37,33 @@ fn create_path(path_points: Vec<Point>) -> Vec<Point> {
                 let x = start.x;
                 let sy = start.y.min(end.y);
                 let ey = start.y.max(end.y);
-                (sy..=ey).into_iter()
+                Some((sy..=ey)
                     .map(|y| Point::new(x, y))
-                    .collect::<Vec<Point>>()
+                    .collect::<Vec<Point>>())
             } else if start.y == end.y {
                 let y = start.y;
                 let sx = start.x.min(end.x);
                 let ex = start.x.max(end.x);
-                (sx..=ex).into_iter()
+                Some((sx..=ex)
                     .map(|x| Point::new(x, y))
-                    .collect::<Vec<Point>>()
+                    .collect::<Vec<Point>>())
             } else {
-                panic!("Path is not a straight line");
+                None
             }
         })
-        .collect()
+        .collect::<Option<Vec<_>>>()
+        .map(|segments| segments.concat())
 }
 
-fn integer(input: &str) -> IResult<&str, i32> {
-    map_res(digit1, str::parse)(input)
+/// Largest coordinate accepted, which keeps the cave small enough to fill with sand.
+const MAX_COORDINATE: i64 = 1000;
+
+fn coordinate(input: &str) -> IResult<&str, i64> {
+    verify(unsigned, |&value| value <= MAX_COORDINATE)(input)
 }
 
 fn point(input: &str) -> IResult<&str, Point> {
-    map(
-        separated_pair(integer, tag(","), integer),
-        |(x, y)| Point::new(x, y)
-    )(input)
+    map(pair_of(coordinate, ","), |(x, y)| Point::new(x, y))(input)
 }
 
 fn path(input: &str) -> IResult<&str, Vec<Point>> {
@@ -68,10 +74,7 @@ fn path(input: &str) -> IResult<&str, Vec<Point>> {
 }
 
 fn path_list(input: &str) -> IResult<&str, Vec<Vec<Point>>> {
-    separated_list1(
-        line_ending,
-        path
-    )(input)
+    lines(path)(input)
 }
 
 #[cfg(test)]
@@ -82,7 +85,7 @@ mod tests {
     fn test_integer() {
         let input = "1234";
 
-        let (rem, int) = integer(input).unwrap();
+        let (rem, int) = unsigned::<i64>(input).unwrap();
 
         assert_eq!(int, 1234);
         assert!(rem.is_empty());
@@ -98,6 +101,11 @@ mod tests {
         assert!(rem.is_empty());
     }
 
+    #[test]
+    fn test_point_too_far() {
+        assert!(point("12,1001").is_err());
+    }
+
     #[test]
     fn test_path() {
         let input = "12,34 -> 34,56 -> 56,78";
@@ -0,0 +1,62 @@
+use aoc_generate::{
+    rand::{seq::SliceRandom, RngExt},
+    Generated, InputRng,
+};
+
+use crate::{Day15, Point};
+
+impl Generated for Day15 {
+    /// `size` sensors around a single spot that none of them covers, besides the four sensors
+    /// that surround it.
+    ///
+    /// The sensors spread over more of the 4000000 wide search area the more of them there are,
+    /// so that small inputs stay quick to solve.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        let span = (size as i32)
+            .saturating_mul(200_000)
+            .clamp(200_000, 4_000_000);
+        let random_point =
+            |rng: &mut InputRng| Point::new(rng.random_range(0..=span), rng.random_range(0..=span));
+
+        let beacon = random_point(rng);
+
+        // Four sensors just out of reach of the distress beacon cover all of its neighbours.
+        let reach = rng.random_range(1..=span / 50);
+        let mut sensors: Vec<(Point, Point)> = [(1, 0), (-1, 0), (0, 1), (0, -1)]
+            .into_iter()
+            .map(|(dx, dy)| {
+                let sensor = beacon + Point::new(dx, dy) * (reach + 1);
+                (sensor, point_at(rng, sensor, reach))
+            })
+            .collect();
+
+        while sensors.len() < size.max(5) {
+            let sensor = random_point(rng);
+            let distance = sensor.manhattan(beacon);
+            if distance > 1 {
+                let reach = rng.random_range(1..distance.min(span / 8).max(2));
+                sensors.push((sensor, point_at(rng, sensor, reach)));
+            }
+        }
+        sensors.shuffle(rng);
+
+        sensors
+            .into_iter()
+            .map(|(sensor, beacon)| {
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                    sensor.x, sensor.y, beacon.x, beacon.y
+                )
+            })
+            .collect()
+    }
+}
+
+/// Random point exactly `distance` away from `center`.
+fn point_at(rng: &mut InputRng, center: Point, distance: i32) -> Point {
+    let dx = rng.random_range(-distance..=distance);
+    let dy = distance - dx.abs();
+    let dy = if rng.random_bool(0.5) { dy } else { -dy };
+
+    center + Point::new(dx, dy)
+}
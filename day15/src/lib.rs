@@ -1,33 +1,70 @@
-mod parser;
+mod generator;
+pub mod parser;
+/// Slow but straightforward solutions to check the optimised ones against.
+pub mod reference;
 
 use std::collections::HashSet;
 
-use parser::parse_input;
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_geometry::{BoundingBox, Point2};
+use tracing::debug;
 
-pub fn solve_part_1(input: &str, query_y: i32) -> usize {
-    let sensors = parse_input(input);
+pub struct Day15;
 
-    let (x_min, x_max) = sensors
-        .iter()
-        .fold((i32::MAX, i32::MIN), |(min, max), sensor| {
-            (
-                sensor.position.x.min(sensor.closest_beacon.x).min(min),
-                sensor.position.x.max(sensor.closest_beacon.x).max(max),
-            )
-        });
+/// Puzzle parameters, which default to the ones of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// Row that part 1 counts the spots without a beacon in.
+    pub row: i32,
+    /// Largest coordinate of the distress beacon in part 2.
+    pub max_coordinate: i32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            row: 2000000,
+            max_coordinate: 4000000,
+        }
+    }
+}
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<Sensor>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parser::parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input, &Params::default())?.into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input, &Params::default())?.into())
+    }
+}
+
+pub fn solve_part_1(sensors: &[Sensor], params: &Params) -> Result<usize, SolveError> {
+    let bounds = BoundingBox::from_points(
+        sensors
+            .iter()
+            .flat_map(|sensor| [sensor.position, sensor.closest_beacon]),
+    )
+    .ok_or_else(|| SolveError::new("No sensors in puzzle input"))?;
     let max_dist = sensors
         .iter()
         .fold(i32::MIN, |max_dist, sensor| sensor.distance.max(max_dist));
 
     let mut count = 0;
-    'outer: for x in (x_min - max_dist)..=(x_max + max_dist) {
-        let p = Point::new(x, query_y);
+    'outer: for x in (bounds.min.x - max_dist)..=(bounds.max.x + max_dist) {
+        let p = Point::new(x, params.row);
         for sensor in sensors.iter() {
             if p == sensor.position || p == sensor.closest_beacon {
                 continue;
             }
 
-            let dist = manhattan(p, sensor.position);
+            let dist = p.manhattan(sensor.position);
             if dist <= sensor.distance {
                 count += 1;
                 continue 'outer;
@@ -35,15 +72,18 @@ pub fn solve_part_1(input: &str, query_y: i32) -> usize {
         }
     }
 
-    count
+    Ok(count)
 }
 
-pub fn solve_part_2(input: &str) -> u128 {
-    let sensors = parse_input(input);
-
+/// Finds the only spot with both coordinates between 0 and `params.max_coordinate` that no
+/// sensor covers.
+///
+/// Such a spot is right next to the edge of some sensor's range, so only those edges are searched.
+pub fn solve_part_2(sensors: &[Sensor], params: &Params) -> Result<u128, SolveError> {
+    let max_coordinate = params.max_coordinate;
     let mut beacon = None;
     'outer: for (i, sensor) in sensors.iter().enumerate() {
-        println!("Sensor {i}...");
+        debug!(sensor = i, x = sensor.position.x, y = sensor.position.y, "Searching the edge of sensor {i} of {}", sensors.len());
 
         let sensor_x = sensor.position.x;
         let sensor_y = sensor.position.y;
@@ -52,22 +92,19 @@ pub fn solve_part_2(input: &str) -> u128 {
             let h = dist - (x - sensor_x);
             for y in HashSet::from([(sensor_y - h), (sensor_y + h)]) {
                 let point = Point::new(x, y);
+                if !(0..=max_coordinate).contains(&x) || !(0..=max_coordinate).contains(&y) {
+                    continue;
+                }
 
-                let points = [
-                    Point::new(point.x - 1, point.y),
-                    Point::new(point.x + 1, point.y),
-                    Point::new(point.x, point.y - 1),
-                    Point::new(point.x, point.y + 1),
-                ];
-
-                if points.into_iter().all(|p| {
+                if point.neighbours_4().into_iter().all(|p| {
                     sensors
                         .iter()
-                        .any(|s| s.distance >= manhattan(s.position, p))
+                        .any(|s| s.distance >= s.position.manhattan(p))
                 }) && sensors
                     .iter()
-                    .all(|s| s.distance < manhattan(s.position, point))
+                    .all(|s| s.distance < s.position.manhattan(point))
                 {
+                    debug!(x, y, "Found the distress beacon");
                     beacon = Some(point);
                     break 'outer;
                 }
@@ -75,39 +112,32 @@ pub fn solve_part_2(input: &str) -> u128 {
         }
     }
 
-    let p = beacon.expect("Failed to find location for beacon");
-    4000000u128 * p.x as u128 + p.y as u128
+    beacon.map(tuning_frequency).ok_or_else(no_beacon)
 }
 
-fn manhattan(p1: Point, p2: Point) -> i32 {
-    (p1.x - p2.x).abs() + (p1.y - p2.y).abs()
+fn tuning_frequency(beacon: Point) -> u128 {
+    4000000u128 * beacon.x as u128 + beacon.y as u128
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point {
-    x: i32,
-    y: i32,
+fn no_beacon() -> SolveError {
+    SolveError::new("Sensors leave no spot for the distress beacon")
 }
 
+type Point = Point2<i32>;
+
 #[derive(Debug, Clone)]
-struct Sensor {
+pub struct Sensor {
     position: Point,
     closest_beacon: Point,
     distance: i32,
 }
 
-impl Point {
-    fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
-    }
-}
-
 impl Sensor {
     fn new(position: Point, closest_beacon: Point) -> Self {
         Self {
             position,
             closest_beacon,
-            distance: manhattan(position, closest_beacon),
+            distance: position.manhattan(closest_beacon),
         }
     }
 }
@@ -1,17 +1,34 @@
-use std::fs;
+use std::process;
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day15::*;
 
 fn main() {
-    let contents = fs::read_to_string("day15/input.txt").expect("Failed to read puzzle input");
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).expect("Failed to read puzzle input");
+    let input = Day15::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    println!("Part 1: {}", solve_part_1(&contents, 2000000));
+    match Day15::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    match Day15::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
 
     const INPUT: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
@@ -29,17 +46,102 @@ Sensor at x=16, y=7: closest beacon is at x=15, y=3
 Sensor at x=14, y=3: closest beacon is at x=15, y=3
 Sensor at x=20, y=1: closest beacon is at x=15, y=3";
 
+    /// The example asks about row 10 and a search area up to 20 instead.
+    const EXAMPLE: Params = Params { row: 10, max_coordinate: 20 };
+
     #[test]
     fn test_part_1() {
-        let sol = solve_part_1(INPUT, 10);
+        let sol = solve_part_1(&parser::parse_input(INPUT).unwrap(), &EXAMPLE);
 
-        assert_eq!(sol, 26);
+        assert_eq!(sol, Ok(26));
     }
 
     #[test]
     fn test_part_2() {
-        let sol = solve_part_2(INPUT);
+        let sol = solve_part_2(&parser::parse_input(INPUT).unwrap(), &EXAMPLE);
+
+        assert_eq!(sol, Ok(56000011));
+    }
+
+    #[test]
+    fn test_reference_example() {
+        let sol = reference::solve_part_2(&parser::parse_input(INPUT).unwrap(), &EXAMPLE);
+
+        assert_eq!(sol, Ok(56000011));
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..3 {
+            let sensors = parser::parse_input(&aoc_generate::generate::<Day15>(seed, 6)).unwrap();
+
+            assert_eq!(sensors.len(), 6);
+            solve_part_2(&sensors, &Params::default()).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_no_answer() {
+        assert!(solve_part_1(&[], &EXAMPLE).is_err());
+
+        let sensors = parser::parse_input("Sensor at x=10, y=10: closest beacon is at x=30, y=10").unwrap();
+        assert!(solve_part_2(&sensors, &EXAMPLE).is_err());
+    }
+
+    /// Input with sensors covering all of a small search area except for a single spot,
+    /// along with the size of the area.
+    fn hidden_beacon() -> impl Strategy<Value = (String, i32)> {
+        let manhattan = |(x0, y0): (i32, i32), (x1, y1): (i32, i32)| (x0 - x1).abs() + (y0 - y1).abs();
+
+        (4..=20i32)
+            .prop_flat_map(|size| {
+                (
+                    Just(size),
+                    (1..size, 1..size),
+                    1..=4i32,
+                    prop::collection::vec(((0..=size, 0..=size), 1..=size), 0..8),
+                )
+            })
+            .prop_map(move |(size, beacon, reach, extra)| {
+                // Four sensors just out of reach of the beacon cover all of its neighbours.
+                let mut sensors: Vec<((i32, i32), i32)> = [(1, 0), (-1, 0), (0, 1), (0, -1)]
+                    .into_iter()
+                    .map(|(dx, dy)| ((beacon.0 + dx * (reach + 1), beacon.1 + dy * (reach + 1)), reach))
+                    .collect();
+                sensors.extend(
+                    extra
+                        .into_iter()
+                        .map(|(sensor, reach)| (sensor, reach.min(manhattan(sensor, beacon) - 1)))
+                        .filter(|&(_, reach)| reach > 0),
+                );
+
+                for x in 0..=size {
+                    for y in 0..=size {
+                        let distance = manhattan((x, y), beacon);
+                        if distance > 1 && sensors.iter().all(|&(sensor, reach)| manhattan(sensor, (x, y)) > reach) {
+                            sensors.push(((x, y), distance - 1));
+                        }
+                    }
+                }
+
+                let input = sensors
+                    .into_iter()
+                    .map(|((x, y), reach)| {
+                        format!("Sensor at x={x}, y={y}: closest beacon is at x={}, y={y}\n", x + reach)
+                    })
+                    .collect();
+                (input, size)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_reference((input, size) in hidden_beacon()) {
+            let sensors = parser::parse_input(&input).unwrap();
+
+            let params = Params { max_coordinate: size, ..Params::default() };
 
-        assert_eq!(sol, 56000011);
+            prop_assert_eq!(solve_part_2(&sensors, &params), reference::solve_part_2(&sensors, &params));
+        }
     }
 }
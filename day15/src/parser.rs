@@ -1,29 +1,33 @@
+use aoc_core::ParseError;
+use aoc_parse::{labelled, lines, parse_all, signed};
 use nom::{
     bytes::complete::tag,
-    character::complete::{char, digit1, line_ending},
-    combinator::{map, map_res, opt, recognize},
-    multi::separated_list0,
-    sequence::{pair, preceded, separated_pair},
+    combinator::{map, verify},
+    sequence::{preceded, separated_pair},
     IResult,
 };
 
 use crate::*;
 
-pub(crate) fn parse_input(input: &str) -> Vec<Sensor> {
-    let (_, sensors) = sensor_list(input).expect("Failed to parse puzzle input");
-    sensors
+pub fn parse_input(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    const EXPECTED: &str = "sensor like `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`";
+
+    parse_all(input, sensor_list, EXPECTED)
 }
 
-fn integer(input: &str) -> IResult<&str, i32> {
-    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+/// Largest distance from the origin accepted, so that distances between points fit in an `i32`.
+const MAX_COORDINATE: i32 = 100_000_000;
+
+fn coordinate(input: &str) -> IResult<&str, i32> {
+    verify(signed, |value: &i32| (-MAX_COORDINATE..=MAX_COORDINATE).contains(value))(input)
 }
 
 fn point(input: &str) -> IResult<&str, Point> {
     map(
         separated_pair(
-            preceded(tag("x="), integer),
+            labelled("x=", coordinate),
             tag(", "),
-            preceded(tag("y="), integer),
+            labelled("y=", coordinate),
         ),
         |(x, y)| Point::new(x, y),
     )(input)
@@ -41,7 +45,7 @@ fn sensor(input: &str) -> IResult<&str, Sensor> {
 }
 
 fn sensor_list(input: &str) -> IResult<&str, Vec<Sensor>> {
-    separated_list0(line_ending, sensor)(input)
+    lines(sensor)(input)
 }
 
 #[cfg(test)]
@@ -52,7 +56,7 @@ mod tests {
     fn test_integer() {
         let input = "-123";
 
-        let (rem, int) = integer(input).unwrap();
+        let (rem, int) = signed::<i32>(input).unwrap();
 
         assert_eq!(int, -123);
         assert!(rem.is_empty());
@@ -68,6 +72,11 @@ mod tests {
         assert!(rem.is_empty());
     }
 
+    #[test]
+    fn test_min_coordinate() {
+        assert!(parse_input("Sensor at x=-2147483648, y=18: closest beacon is at x=-2, y=15").is_err());
+    }
+
     #[test]
     fn test_sensor() {
         let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
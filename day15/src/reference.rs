@@ -0,0 +1,17 @@
+use aoc_core::SolveError;
+
+use crate::{no_beacon, tuning_frequency, Params, Point, Sensor};
+
+/// Checks every spot of the search area.
+pub fn solve_part_2(sensors: &[Sensor], params: &Params) -> Result<u128, SolveError> {
+    let max_coordinate = params.max_coordinate;
+    (0..=max_coordinate)
+        .flat_map(|x| (0..=max_coordinate).map(move |y| Point::new(x, y)))
+        .find(|&point| {
+            sensors
+                .iter()
+                .all(|s| s.distance < s.position.manhattan(point))
+        })
+        .map(tuning_frequency)
+        .ok_or_else(no_beacon)
+}
@@ -0,0 +1,76 @@
+use std::collections::BTreeSet;
+
+use aoc_generate::{
+    rand::{
+        seq::{index, SliceRandom},
+        RngExt,
+    },
+    Generated, InputRng, UPPERCASE,
+};
+
+use crate::Day16;
+
+impl Generated for Day16 {
+    /// `size` valves connected by tunnels, with up to 15 of them worth opening like in the
+    /// puzzle.
+    ///
+    /// Tunnels first connect each valve to one named before it, so every valve can be reached
+    /// from `AA`, and then some more are dug at random.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        let mut names: Vec<String> = UPPERCASE
+            .iter()
+            .flat_map(|&first| {
+                UPPERCASE
+                    .iter()
+                    .map(move |&second| String::from_utf8(vec![first, second]).unwrap())
+            })
+            .filter(|name| name != "AA")
+            .collect();
+        names.shuffle(rng);
+        names.truncate(size.clamp(2, 676) - 1);
+        names.insert(0, "AA".to_string());
+
+        let count = names.len();
+        let mut tunnels = vec![BTreeSet::new(); count];
+        let mut dig = |from: usize, to: usize| {
+            if from != to {
+                tunnels[from].insert(to);
+                tunnels[to].insert(from);
+            }
+        };
+        for valve in 1..count {
+            dig(valve, rng.random_range(0..valve));
+        }
+        for _ in 0..count / 4 {
+            dig(rng.random_range(0..count), rng.random_range(0..count));
+        }
+
+        let mut flow_rates = vec![0; count];
+        let useful = (count / 3).clamp(1, 15).min(count - 1);
+        for valve in index::sample(rng, count - 1, useful) {
+            flow_rates[valve + 1] = rng.random_range(3..=25);
+        }
+
+        let mut scan: Vec<String> = (0..count)
+            .map(|valve| {
+                let adjacent: Vec<&str> =
+                    tunnels[valve].iter().map(|&i| names[i].as_str()).collect();
+                let tunnels = if adjacent.len() == 1 {
+                    "tunnel leads to valve"
+                } else {
+                    "tunnels lead to valves"
+                };
+
+                format!(
+                    "Valve {} has flow rate={}; {tunnels} {}\n",
+                    names[valve],
+                    flow_rates[valve],
+                    adjacent.join(", ")
+                )
+            })
+            .collect();
+        scan.shuffle(rng);
+
+        scan.concat()
+    }
+}
@@ -1,25 +1,70 @@
-mod parser;
+mod generator;
+pub mod parser;
+/// Slow but straightforward solutions to check the optimised ones against.
+pub mod reference;
 
-use std::collections::{hash_map::Entry, BTreeSet, HashMap, VecDeque};
+use std::collections::{BTreeSet, HashMap, VecDeque};
 
-use parser::parse_input;
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_search::all_pairs;
+use tracing::debug;
 
-pub fn solve_part_1(input: &str) -> u32 {
-    let valves = parse_input(input);
-    let cave = Cave::new(valves);
+pub struct Day16;
 
-    *simulate_flows(&cave, 30)
+/// Puzzle parameters, which default to the ones of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// Valve we start at, along with the elephant in part 2.
+    pub start: String,
+    /// Minutes before the volcano erupts in part 1.
+    pub part_1_minutes: u32,
+    /// Minutes left in part 2 after teaching the elephant.
+    pub part_2_minutes: u32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            start: "AA".to_string(),
+            part_1_minutes: 30,
+            part_2_minutes: 26,
+        }
+    }
+}
+
+impl Solution for Day16 {
+    type Input<'a> = Vec<Valve<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parser::parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input, &Params::default())?.into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input, &Params::default())?.into())
+    }
+}
+
+pub fn solve_part_1(valves: &[Valve], params: &Params) -> Result<u32, SolveError> {
+    let cave = Cave::new(valves.to_vec());
+
+    Ok(*simulate_flows(&cave, &params.start, params.part_1_minutes)?
         .values()
         .max()
-        .expect("Failed to find max pressure released")
+        .expect("Failed to find max pressure released"))
 }
 
+/// Most valves worth opening that part 2 tries every split of.
+const MAX_USEFUL_VALVES: usize = 24;
+
 #[allow(clippy::map_entry)]
-pub fn solve_part_2(input: &str) -> u32 {
-    let valves = parse_input(input);
-    let cave = Cave::new(valves);
+pub fn solve_part_2(valves: &[Valve], params: &Params) -> Result<u32, SolveError> {
+    let cave = Cave::new(valves.to_vec());
 
-    let flows = simulate_flows(&cave, 26);
+    let flows = simulate_flows(&cave, &params.start, params.part_2_minutes)?;
 
     let mut table: HashMap<BTreeSet<&str>, u32> = HashMap::new();
     for ((_, _, open_valves), flow) in flows {
@@ -30,39 +75,61 @@ pub fn solve_part_2(input: &str) -> u32 {
         }
     }
 
-    let mut result = 0u32;
-    let all_valve_names: BTreeSet<&str> = cave.valves.values().map(|v| v.name).collect();
-    for valve_names in power_set(&all_valve_names.iter().collect::<Vec<_>>()) {
-        let other_names: BTreeSet<&str> = all_valve_names
-            .clone()
-            .into_iter()
-            .filter(|v| !valve_names.contains(&&v))
-            .collect();
-
-        let val = *table.get(&other_names).unwrap_or(&0);
-
-        result = result.max(val);
+    // Only valves with positive flow rate are worth opening, so they are the only ones
+    // that can be split between us and the elephant.
+    let useful_valves: Vec<&str> = cave
+        .valves
+        .values()
+        .filter(|v| v.flow_rate > 0)
+        .map(|v| v.name)
+        .collect();
+    if useful_valves.len() > MAX_USEFUL_VALVES {
+        return Err(SolveError::new(format!(
+            "Too many valves worth opening ({}) to try every split of them",
+            useful_valves.len()
+        )));
+    }
 
-        let mut valve_names_2: BTreeSet<&str> = valve_names.iter().map(|&&&v| v).collect();
-        while !valve_names_2.is_empty() {
-            let val2 = *table.get(&valve_names_2).unwrap_or(&0);
-            result = result.max(val + val2);
-            valve_names_2.pop_last();
+    // `best[mask]` holds the most pressure released by opening any subset of valves in `mask`.
+    let mut best: Vec<u32> = power_set(&useful_valves)
+        .map(|valve_names| {
+            let open_valves: BTreeSet<&str> = valve_names.into_iter().copied().collect();
+            *table.get(&open_valves).unwrap_or(&0)
+        })
+        .collect();
+    for mask in 0..best.len() {
+        for bit in 0..useful_valves.len() {
+            if (mask >> bit) & 1 == 1 {
+                best[mask] = best[mask].max(best[mask ^ (1 << bit)]);
+            }
         }
     }
 
-    result
+    let all_mask = best.len() - 1;
+    Ok(best.iter()
+        .enumerate()
+        .map(|(mask, flow)| flow + best[all_mask ^ mask])
+        .max()
+        .expect("Failed to find max pressure released"))
 }
 
+/// Current valve, minutes left and open valves.
+type State<'a> = (&'a str, u32, BTreeSet<&'a str>);
+
 fn simulate_flows<'a>(
     cave: &'a Cave,
+    start: &str,
     time: u32,
-) -> HashMap<(&'a str, u32, BTreeSet<&'a str>), u32> {
-    // (current_valve, time_left, open_valves)
+) -> Result<HashMap<State<'a>, u32>, SolveError> {
+    let (&start, _) = cave
+        .valves
+        .get_key_value(start)
+        .ok_or_else(|| SolveError::new(format!("No valve {start} to start from")))?;
+
     let mut dynamic = HashMap::new();
-    dynamic.insert(("AA", time, BTreeSet::new()), 0);
+    dynamic.insert((start, time, BTreeSet::new()), 0);
 
-    let mut queue = VecDeque::from([("AA", time, BTreeSet::new())]);
+    let mut queue = VecDeque::from([(start, time, BTreeSet::new())]);
     while let Some(entry) = queue.pop_front() {
         let flow = dynamic[&entry];
 
@@ -71,7 +138,9 @@ fn simulate_flows<'a>(
         let valve = &cave.valves[&valve_name];
 
         for &other_name in cave.valves.keys() {
-            let move_time = cave.distances[&(valve_name, other_name)];
+            let Some(&move_time) = cave.distances.get(&(valve_name, other_name)) else {
+                continue;
+            };
             if move_time <= time_left {
                 let key = (other_name, time_left - move_time, open_valves.clone());
                 if !dynamic.contains_key(&key) || dynamic[&key] < flow {
@@ -93,7 +162,8 @@ fn simulate_flows<'a>(
         }
     }
 
-    dynamic
+    debug!(states = dynamic.len(), "Simulated flows from {start} over {time} minutes");
+    Ok(dynamic)
 }
 
 fn power_set<T>(vec: &[T]) -> impl Iterator<Item = Vec<&T>> {
@@ -107,7 +177,7 @@ fn power_set<T>(vec: &[T]) -> impl Iterator<Item = Vec<&T>> {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Valve<'a> {
+pub struct Valve<'a> {
     name: &'a str,
     flow_rate: u32,
     adjacencies: Vec<&'a str>,
@@ -133,30 +203,9 @@ impl<'a> Cave<'a> {
     fn new(valves: Vec<Valve<'a>>) -> Self {
         let valves: HashMap<_, _> = valves.into_iter().map(|v| (v.name, v)).collect();
 
-        let num_valves = valves.len();
-        let mut distances = HashMap::with_capacity(num_valves * num_valves);
-        for &start_valve_name in valves.keys() {
-            distances.insert((start_valve_name, start_valve_name), 0);
-
-            let mut to_consider = VecDeque::from([start_valve_name]);
-            while let Some(valve_name) = to_consider.pop_front() {
-                let root_key = (start_valve_name, valve_name);
-
-                for &adjacent_name in &valves[valve_name].adjacencies {
-                    let adj_key = (start_valve_name, adjacent_name);
-                    let dist = distances[&root_key] + 1;
-                    if let Entry::Vacant(entry) = distances.entry(adj_key) {
-                        entry.insert(dist);
-                        to_consider.push_back(adjacent_name);
-                    }
-                }
-            }
-        }
-
-        // let valves = valves
-        //     .into_iter()
-        //     .filter(|(_, v)| v.flow_rate > 0 || v.name == "AA")
-        //     .collect();
+        let distances = all_pairs(valves.keys().copied(), |&name| {
+            valves[name].adjacencies.iter().map(|&adjacent| (adjacent, 1))
+        });
 
         Self { valves, distances }
     }
@@ -1,17 +1,34 @@
-use std::fs;
+use std::process;
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day16::*;
 
 fn main() {
-    let contents = fs::read_to_string("day16/input.txt").expect("Failed to read puzzle input");
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).expect("Failed to read puzzle input");
+    let input = Day16::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day16::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    match Day16::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
 
     const INPUT: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
@@ -27,15 +44,80 @@ Valve JJ has flow rate=21; tunnel leads to valve II";
 
     #[test]
     fn test_part_1() {
-        let sol = solve_part_1(INPUT);
+        let sol = solve_part_1(&parser::parse_input(INPUT).unwrap(), &Params::default());
 
-        assert_eq!(sol, 1651);
+        assert_eq!(sol, Ok(1651));
     }
 
     #[test]
     fn test_part_2() {
-        let sol = solve_part_2(INPUT);
+        let sol = solve_part_2(&parser::parse_input(INPUT).unwrap(), &Params::default());
+
+        assert_eq!(sol, Ok(1707));
+    }
+
+    #[test]
+    fn test_part_2_single_useful_valve() {
+        let input = "Valve AA has flow rate=0; tunnel leads to valve BB
+Valve BB has flow rate=10; tunnel leads to valve AA";
+
+        assert_eq!(solve_part_2(&parser::parse_input(input).unwrap(), &Params::default()), Ok(240));
+    }
+
+    #[test]
+    fn test_part_2_no_useful_valves() {
+        let input = "Valve AA has flow rate=0; tunnel leads to valve BB
+Valve BB has flow rate=0; tunnel leads to valve AA";
+
+        assert_eq!(solve_part_2(&parser::parse_input(input).unwrap(), &Params::default()), Ok(0));
+    }
+
+    #[test]
+    fn test_reference_example() {
+        let valves = parser::parse_input(INPUT).unwrap();
+
+        assert_eq!(reference::solve_part_1(&valves, &Params::default()), 1651);
+        assert_eq!(reference::solve_part_2(&valves, &Params::default()), 1707);
+    }
+
+    #[test]
+    fn test_params() {
+        let valves = parser::parse_input(INPUT).unwrap();
+        let params = Params {
+            start: "JJ".to_string(),
+            part_1_minutes: 20,
+            part_2_minutes: 12,
+        };
+
+        assert_eq!(solve_part_1(&valves, &params), Ok(reference::solve_part_1(&valves, &params)));
+        assert_eq!(solve_part_2(&valves, &params), Ok(reference::solve_part_2(&valves, &params)));
+
+        let params = Params { start: "ZZ".to_string(), ..Params::default() };
+        assert!(solve_part_1(&valves, &params).is_err());
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..5 {
+            let input = aoc_generate::generate::<Day16>(seed, 8);
+            let valves = parser::parse_input(&input).unwrap();
+
+            assert_eq!(valves.len(), 8);
+            assert!(solve_part_1(&valves, &Params::default()).unwrap() > 0);
+            solve_part_2(&valves, &Params::default()).unwrap();
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn test_reference(seed: u64, size in 2..=10usize) {
+            let input = aoc_generate::generate::<Day16>(seed, size);
+            let valves = parser::parse_input(&input).unwrap();
 
-        assert_eq!(sol, 1707);
+            prop_assert_eq!(solve_part_1(&valves, &Params::default()).unwrap(), reference::solve_part_1(&valves, &Params::default()));
+            prop_assert_eq!(solve_part_2(&valves, &Params::default()).unwrap(), reference::solve_part_2(&valves, &Params::default()));
+        }
     }
 }
@@ -1,40 +1,51 @@
+use aoc_core::ParseError;
+use aoc_parse::{comma_separated, identifier, lines, parse_all, unsigned};
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take},
-    character::{
-        complete::{digit1, line_ending},
-        streaming::space1,
-    },
-    combinator::{map, map_res},
-    multi::separated_list0,
+    bytes::complete::tag,
+    character::complete::space1,
+    combinator::{map, verify},
     sequence::{delimited, preceded, tuple},
     IResult,
 };
 
 use crate::*;
 
-pub(crate) fn parse_input(input: &str) -> Vec<Valve> {
-    let (_, vertices) = valve_list(input).expect("Failed to parse puzzle input");
-    vertices
-}
+pub fn parse_input(input: &str) -> Result<Vec<Valve<'_>>, ParseError> {
+    const EXPECTED: &str = "valve like `Valve AA has flow rate=0; tunnels lead to valves DD, II`";
+
+    let valves = parse_all(input, valve_list, EXPECTED)?;
 
-fn integer(input: &str) -> IResult<&str, u32> {
-    map_res(digit1, str::parse)(input)
+    let known = |name: &str| valves.iter().any(|valve| valve.name == name);
+    for valve in &valves {
+        if let Some(unknown) = valve.adjacencies.iter().find(|&&name| !known(name)) {
+            return Err(ParseError::new(input, unknown, "name of a valve in the cave"));
+        }
+    }
+
+    Ok(valves)
 }
 
 fn name(input: &str) -> IResult<&str, &str> {
-    take(2usize)(input)
+    identifier(2)(input)
+}
+
+/// Largest flow rate accepted, so that the pressure released fits in a `u32`.
+const MAX_FLOW_RATE: u32 = 1000;
+
+fn flow_rate(input: &str) -> IResult<&str, u32> {
+    verify(unsigned, |&rate| rate <= MAX_FLOW_RATE)(input)
 }
 
 fn name_list(input: &str) -> IResult<&str, Vec<&str>> {
-    separated_list0(tag(", "), name)(input)
+    comma_separated(name)(input)
 }
 
-fn valve(input: &str) -> IResult<&str, Valve> {
+fn valve(input: &str) -> IResult<&str, Valve<'_>> {
     map(
         tuple((
             preceded(tag("Valve "), name),
-            delimited(tag(" has flow rate="), integer, tag("; ")),
+            delimited(tag(" has flow rate="), flow_rate, tag("; ")),
             preceded(
                 tuple((
                     alt((tag("tunnels lead"), tag("tunnel leads"))),
@@ -49,8 +60,8 @@ fn valve(input: &str) -> IResult<&str, Valve> {
     )(input)
 }
 
-fn valve_list(input: &str) -> IResult<&str, Vec<Valve>> {
-    separated_list0(line_ending, valve)(input)
+fn valve_list(input: &str) -> IResult<&str, Vec<Valve<'_>>> {
+    lines(valve)(input)
 }
 
 #[cfg(test)]
@@ -61,7 +72,7 @@ mod tests {
     fn test_integer() {
         let input = "123";
 
-        let (rem, int) = integer(input).unwrap();
+        let (rem, int) = unsigned::<u32>(input).unwrap();
 
         assert_eq!(int, 123);
         assert!(rem.is_empty());
@@ -113,4 +124,10 @@ Valve BB has flow rate=13; tunnels lead to valves CC, AA";
         );
         assert!(rem.is_empty());
     }
+
+    #[test]
+    fn test_parse_input_error() {
+        let err = parse_input("Valve AA has flow rate=0; tunnels lead to valves AA, CC").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 54));
+    }
 }
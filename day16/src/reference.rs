@@ -0,0 +1,80 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{Params, Valve};
+
+/// Tries every order of opening the valves.
+pub fn solve_part_1(valves: &[Valve], params: &Params) -> u32 {
+    let useful = useful_valves(valves);
+    best_pressure(valves, &params.start, params.part_1_minutes, &useful)
+}
+
+/// Tries every way of splitting the valves between us and the elephant, each opening
+/// their share in the best order.
+pub fn solve_part_2(valves: &[Valve], params: &Params) -> u32 {
+    let useful = useful_valves(valves);
+
+    (0..1usize << useful.len())
+        .map(|mask| {
+            let (mine, elephants): (Vec<_>, Vec<_>) = useful
+                .iter()
+                .enumerate()
+                .partition(|&(i, _)| (mask >> i) & 1 == 1);
+            let mine: Vec<&str> = mine.into_iter().map(|(_, &name)| name).collect();
+            let elephants: Vec<&str> = elephants.into_iter().map(|(_, &name)| name).collect();
+
+            best_pressure(valves, &params.start, params.part_2_minutes, &mine)
+                + best_pressure(valves, &params.start, params.part_2_minutes, &elephants)
+        })
+        .max()
+        .expect("Failed to find max pressure released")
+}
+
+fn useful_valves<'a>(valves: &[Valve<'a>]) -> Vec<&'a str> {
+    valves
+        .iter()
+        .filter(|valve| valve.flow_rate > 0)
+        .map(|valve| valve.name)
+        .collect()
+}
+
+/// Most pressure released by walking from `start` and opening some of `closed` in `time_left`.
+fn best_pressure(valves: &[Valve], start: &str, time_left: u32, closed: &[&str]) -> u32 {
+    closed
+        .iter()
+        .filter_map(|&next| {
+            let time_left = time_left.checked_sub(distance(valves, start, next)? + 1)?;
+            let rest: Vec<&str> = closed.iter().copied().filter(|&name| name != next).collect();
+
+            Some(time_left * flow_rate(valves, next) + best_pressure(valves, next, time_left, &rest))
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn flow_rate(valves: &[Valve], name: &str) -> u32 {
+    valves
+        .iter()
+        .find(|valve| valve.name == name)
+        .map_or(0, |valve| valve.flow_rate)
+}
+
+/// Number of tunnels on the shortest way from `from` to `to`.
+fn distance(valves: &[Valve], from: &str, to: &str) -> Option<u32> {
+    let mut distances = HashMap::from([(from, 0)]);
+    let mut queue = VecDeque::from([from]);
+    while let Some(name) = queue.pop_front() {
+        if name == to {
+            return Some(distances[name]);
+        }
+
+        let valve = valves.iter().find(|valve| valve.name == name)?;
+        for &adjacent in &valve.adjacencies {
+            if !distances.contains_key(adjacent) {
+                distances.insert(adjacent, distances[name] + 1);
+                queue.push_back(adjacent);
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,14 @@
+use aoc_generate::{rand::RngExt, Generated, InputRng};
+
+use crate::Day17;
+
+impl Generated for Day17 {
+    /// Jet pattern of `size` pushes.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        let jets: String = (0..size.max(1))
+            .map(|_| if rng.random_bool(0.5) { '<' } else { '>' })
+            .collect();
+
+        jets + "\n"
+    }
+}
@@ -1,19 +1,109 @@
-use std::ops::AddAssign;
+use std::collections::HashMap;
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_geometry::{BoundingBox, Direction, Point2};
+use aoc_visual::{Animated, Frames, NoFrames};
+use tracing::debug;
+
+mod generator;
+/// Slow but straightforward solutions to check the optimised ones against.
+pub mod reference;
+
+pub struct Day17;
+
+/// Puzzle parameters, which default to the ones of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    pub part_1_rocks: usize,
+    pub part_2_rocks: usize,
+    /// Width of the cave, which fits the widest rock next to the gap it spawns at for 6 to 64.
+    pub cave_width: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part_1_rocks: 2022,
+            part_2_rocks: 1_000_000_000_000,
+            cave_width: 7,
+        }
+    }
+}
+
+impl Solution for Day17 {
+    type Input<'a> = Vec<Direction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
 
-fn parse_input(input: &str) -> Vec<Direction> {
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input, &Params::default())?.into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input, &Params::default())?.into())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Direction>, ParseError> {
     input
-        .chars()
-        .filter(|&ch| ch != '\n' && ch != '\r')
-        .map(|ch| match ch {
-            '<' => Direction::Left,
-            '>' => Direction::Right,
-            _ => panic!("Invalid character encountered in puzzle input"),
+        .char_indices()
+        .filter(|&(_, ch)| ch != '\n' && ch != '\r')
+        .map(|(offset, ch)| match ch {
+            '<' => Ok(Direction::Left),
+            '>' => Ok(Direction::Right),
+            _ => Err(ParseError::at_offset(input, offset, "jet direction (`<` or `>`)")),
         })
         .collect()
 }
 
-pub fn solve_part_1(input: &str) -> i64 {
-    let jets = parse_input(input);
+impl Animated for Day17 {
+    fn animate(
+        input: &Self::Input<'_>,
+        part: u8,
+        frames: &mut impl Frames,
+    ) -> Result<Answer, SolveError> {
+        let params = Params::default();
+        let num_rocks = match part {
+            1 => params.part_1_rocks,
+            2 => params.part_2_rocks,
+            _ => return Err(SolveError::new(format!("No part {part} to animate"))),
+        };
+
+        Ok(simulate(input, num_rocks, params.cave_width, frames)?.into())
+    }
+}
+
+pub fn solve_part_1(jets: &[Direction], params: &Params) -> Result<i64, SolveError> {
+    simulate(jets, params.part_1_rocks, params.cave_width, &mut NoFrames)
+}
+
+pub fn solve_part_2(jets: &[Direction], params: &Params) -> Result<i64, SolveError> {
+    simulate(jets, params.part_2_rocks, params.cave_width, &mut NoFrames)
+}
+
+/// Drops `num_rocks` rocks into a cave `width` tiles wide and returns the height of the
+/// resulting stack.
+///
+/// Once the same rock shape falls with the same jet and the same top of the stack as before,
+/// the whole cycle is skipped at once, since it will keep repeating until the end.
+/// The top of the cave is shown in `frames` after every jet push and fall.
+pub fn simulate(
+    jets: &[Direction],
+    num_rocks: usize,
+    width: usize,
+    frames: &mut impl Frames,
+) -> Result<i64, SolveError> {
+    if !(6..=64).contains(&width) {
+        return Err(SolveError::new(format!("Cave cannot be {width} tiles wide, only 6 to 64")));
+    }
+    if num_rocks == 0 {
+        return Ok(0);
+    }
+    if jets.is_empty() {
+        return Err(SolveError::new("No jets to push the rocks around"));
+    }
 
     let mut rocks_queue = Vec::from([
         Rock::horizontal,
@@ -21,105 +111,164 @@ pub fn solve_part_1(input: &str) -> i64 {
         Rock::l_shape,
         Rock::vertical,
         Rock::sqaure,
-    ]).into_iter().cycle();
-
-    let mut num_rocks = 0;
-    let mut rocks = vec![rocks_queue.next().unwrap()(Point::new(2, 3))];
-    for &jet_dir in jets.iter().cycle() {
-        if !check_collision(&rocks, jet_dir) {
-            rocks.last_mut().unwrap().push(jet_dir);
+    ]).into_iter().enumerate().cycle();
+
+    let mut seen = HashMap::new();
+    let mut skipped_height = 0;
+
+    let mut stack = Stack::new(width as i64);
+    let mut num_fallen = 0;
+    let (mut shape, spawn) = rocks_queue.next().unwrap();
+    let mut rock = spawn(Point::new(2, 3));
+    for (jet, &jet_dir) in jets.iter().enumerate().cycle() {
+        if !check_collision(&stack, &rock, jet_dir) {
+            rock.push(jet_dir);
         }
 
-        if !check_collision(&rocks, Direction::Down) {
-            rocks.last_mut().unwrap().push(Direction::Down);
+        if !check_collision(&stack, &rock, Direction::Down) {
+            rock.push(Direction::Down);
+            frames.frame(|| render_cave(&stack, &rock));
         } else {
-            num_rocks += 1;
-            if num_rocks >= 2022 {
+            for &tile in &rock.tiles {
+                stack.insert(tile);
+            }
+
+            num_fallen += 1;
+            if num_fallen >= num_rocks {
                 break;
             }
 
-            let height = stack_height(&rocks);
-            rocks.push(rocks_queue.next().unwrap()(Point::new(2, height + 3)));
+            let height = stack.height();
+            if skipped_height == 0 {
+                let key = (shape, jet, stack_top(&stack, height));
+                if let Some((prev_fallen, prev_height)) = seen.insert(key, (num_fallen, height)) {
+                    let cycle_len = num_fallen - prev_fallen;
+                    let num_cycles = (num_rocks - num_fallen) / cycle_len;
+                    debug!(cycle_len, num_cycles, "Found a cycle after {prev_fallen} rocks, skipping ahead");
+                    num_fallen += num_cycles * cycle_len;
+                    skipped_height = num_cycles as i64 * (height - prev_height);
+
+                    if num_fallen >= num_rocks {
+                        break;
+                    }
+                }
+            }
+
+            let (next_shape, spawn) = rocks_queue.next().unwrap();
+            shape = next_shape;
+            rock = spawn(Point::new(2, height + 3));
         }
     }
 
-    stack_height(&rocks)
+    Ok(stack.height() + skipped_height)
 }
 
-pub fn solve_part_2(input: &str) -> i64 {
-    todo!()
+/// Returns the tiles of the stack and cave floor that later rocks could still touch,
+/// relative to the stack height.
+///
+/// They are found by flooding the empty space down from the top of the stack, so they decide
+/// how every later rock falls, unless it falls down a well deeper than `MAX_DEPTH`.
+fn stack_top(stack: &Stack, height: i64) -> Vec<Point> {
+    const MAX_DEPTH: i64 = 1000;
+
+    let mut queue: Vec<Point> = (0..stack.width).map(|x| Point::new(x, height)).collect();
+    let mut seen = Stack::new(stack.width);
+    let mut top = Vec::new();
+    while let Some(tile) = queue.pop() {
+        for dir in [Direction::Left, Direction::Right, Direction::Down] {
+            let next = tile + dir.delta();
+            // Tiles are marked as seen by their depth below the top of the stack.
+            let depth = Point::new(next.x, height - next.y);
+            if !(0..stack.width).contains(&next.x) || depth.y > MAX_DEPTH || seen.contains(depth) {
+                continue;
+            }
+            seen.insert(depth);
+
+            if next.y < 0 || stack.contains(next) {
+                top.push(Point::new(next.x, next.y - height));
+            } else {
+                queue.push(next);
+            }
+        }
+    }
+    top.sort_by_key(|tile| (tile.y, tile.x));
+
+    top
 }
 
-/// Checks whether the last rock in `cave` would collide with other rocks or cave walls
+/// Checks whether `rock` would collide with the `stack` of fallen rocks or cave walls
 /// if it were to move one tile in `dir` direction.
-fn check_collision(cave: &Vec<Rock>, dir: Direction) -> bool {
-    let rock = cave.last().expect("Cave has no rocks");
+fn check_collision(stack: &Stack, rock: &Rock, dir: Direction) -> bool {
+    let delta = dir.delta();
 
-    let delta_p = dir.delta();
-    let dx = delta_p.x;
-    let dy = delta_p.y;
-
-    let rock_pos = rock.position;
-    if rock_pos.x + dx < 0 || rock_pos.x + dx + rock.width > 7 || rock_pos.y + dy < 0 {
+    let rock_pos = rock.position + delta;
+    if rock_pos.x < 0 || rock_pos.x + rock.width > stack.width || rock_pos.y < 0 {
         return true;
     }
 
-    if cave.len() < 2 {
-        return false;
-    }
-
-    cave[0..cave.len() - 1]
+    rock.tiles
         .iter()
-        .skip((cave.len() as isize - 40).max(0) as usize)
-        .flat_map(|r| &r.tiles)
-        .any(|t1| {
-            rock.tiles
-                .iter()
-                .any(|t0| &Point::new(t0.x + dx, t0.y + dy) == t1)
-        })
+        .any(|&tile| stack.contains(tile + delta))
 }
 
-fn stack_height(cave: &[Rock]) -> i64 {
-    cave.iter()
-        .skip((cave.len() as isize - 40).max(0) as usize)
-        .flat_map(|rock| &rock.tiles)
-        .map(|tile| tile.y)
-        .max()
-        .expect("Cave is empty") + 1
-}
+/// Draws the top rows of the cave, including the falling rock.
+fn render_cave(stack: &Stack, rock: &Rock) -> String {
+    const ROWS: i64 = 24;
+
+    let top = rock.tiles.iter().map(|tile| tile.y).fold(stack.height() - 1, i64::max);
+    let bottom = (top - ROWS + 1).max(0);
+
+    let mut out = String::new();
+    for y in (bottom..=top).rev() {
+        out.push('|');
+        out.extend((0..stack.width).map(|x| {
+            let tile = Point::new(x, y);
+            if stack.contains(tile) || rock.tiles.contains(&tile) { '#' } else { '.' }
+        }));
+        out.push_str("|\n");
+    }
 
-#[allow(unused)]
-fn print_cave(cave: &[Rock]) {
-    let points: Vec<_> = cave.iter().flat_map(|rock| &rock.tiles).collect();
-    let height = points.iter().max_by_key(|p| p.y).unwrap().y;
-
-    for y in (0..=height).rev() {
-        print!("|");
-        for x in 0..7 {
-            let p = Point::new(x, y);
-            if points.contains(&&p) {
-                print!("#");
-            } else {
-                print!(".");
-            }
-        }
-        println!("|");
+    if bottom == 0 {
+        out.push('+');
+        out.extend((0..stack.width).map(|_| '-'));
+        out.push_str("+\n");
     }
 
-    println!("+-------+\n");
+    out
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Point {
-    x: i64,
-    y: i64,
+type Point = Point2<i64>;
+
+/// Tiles of the fallen rocks, as one row of bits per level of the cave.
+#[derive(Debug)]
+struct Stack {
+    rows: Vec<u64>,
+    width: i64,
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    Left,
-    Right,
-    Down,
+impl Stack {
+    fn new(width: i64) -> Self {
+        Self { rows: Vec::new(), width }
+    }
+
+    fn height(&self) -> i64 {
+        self.rows.len() as i64
+    }
+
+    fn contains(&self, tile: Point) -> bool {
+        usize::try_from(tile.y)
+            .ok()
+            .and_then(|y| self.rows.get(y))
+            .is_some_and(|row| (row >> tile.x) & 1 == 1)
+    }
+
+    fn insert(&mut self, tile: Point) {
+        let y = tile.y as usize;
+        if y >= self.rows.len() {
+            self.rows.resize(y + 1, 0);
+        }
+        self.rows[y] |= 1 << tile.x;
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -130,40 +279,17 @@ struct Rock {
     _height: i64,
 }
 
-impl Point {
-    fn new(x: i64, y: i64) -> Self {
-        Self { x, y }
-    }
-}
-
-impl AddAssign for Point {
-    fn add_assign(&mut self, rhs: Point) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-    }
-}
-
 #[allow(clippy::identity_op)]
 impl Rock {
     fn new(tiles: Vec<Point>) -> Self {
-        let (x_min, x_max, y_min, y_max) = tiles.iter().fold(
-            (i64::MAX, i64::MIN, i64::MAX, i64::MIN),
-            |(x_min, x_max, y_min, y_max), tile| {
-                (
-                    tile.x.min(x_min),
-                    tile.x.max(x_max),
-                    tile.y.min(y_min),
-                    tile.y.max(y_max),
-                )
-            },
-        );
-
-        let width = x_max - x_min + 1;
-        let height = y_max - y_min + 1;
+        let bounds = BoundingBox::from_points(tiles.iter().copied()).expect("Rock has no tiles");
+
+        let width = bounds.max.x - bounds.min.x + 1;
+        let height = bounds.max.y - bounds.min.y + 1;
 
         Self {
             tiles,
-            position: Point::new(x_min, y_min),
+            position: bounds.min,
             width,
             _height: height,
         }
@@ -224,13 +350,3 @@ impl Rock {
         }
     }
 }
-
-impl Direction {
-    fn delta(&self) -> Point {
-        match self {
-            Direction::Left => Point::new(-1, 0),
-            Direction::Right => Point::new(1, 0),
-            Direction::Down => Point::new(0, -1),
-        }
-    }
-}
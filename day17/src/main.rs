@@ -1,32 +1,125 @@
-use std::fs;
+use std::process;
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day17::*;
 
 fn main() {
-    let contents = fs::read_to_string("day17/input.txt").expect("Failed to read puzzle input");
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).expect("Failed to read puzzle input");
+    let input = Day17::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day17::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    match Day17::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::Answer;
+    use aoc_visual::{Animated, Animation, NoFrames};
+    use proptest::prelude::*;
+
     use super::*;
 
     const INPUT: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
 
     #[test]
     fn test_part_1() {
-        let sol = solve_part_1(INPUT);
+        let sol = solve_part_1(&parse_input(INPUT).unwrap(), &Params::default());
 
-        assert_eq!(sol, 3068);
+        assert_eq!(sol, Ok(3068));
     }
 
     #[test]
     fn test_part_2() {
-        let sol = solve_part_2(INPUT);
+        let sol = solve_part_2(&parse_input(INPUT).unwrap(), &Params::default());
+
+        assert_eq!(sol, Ok(1_514_285_714_288));
+    }
+
+    #[test]
+    fn test_repeated_jets() {
+        // Repeating the jet pattern blows the same jets, but cycles show up at other jet indices.
+        let input = INPUT.repeat(2);
+
+        assert_eq!(solve_part_1(&parse_input(&input).unwrap(), &Params::default()), Ok(3068));
+        assert_eq!(solve_part_2(&parse_input(&input).unwrap(), &Params::default()), Ok(1_514_285_714_288));
+    }
+
+    #[test]
+    fn test_reference_example() {
+        assert_eq!(reference::simulate(&parse_input(INPUT).unwrap(), 2022, 7), 3068);
+    }
+
+    #[test]
+    fn test_no_rocks() {
+        let jets = parse_input(INPUT).unwrap();
+
+        assert_eq!(simulate(&jets, 0, 7, &mut NoFrames), Ok(reference::simulate(&jets, 0, 7)));
+        assert_eq!(simulate(&[], 0, 7, &mut NoFrames), Ok(0));
+        assert!(simulate(&[], 1, 7, &mut NoFrames).is_err());
+    }
+
+    #[test]
+    fn test_cave_width() {
+        let jets = parse_input(INPUT).unwrap();
+        let params = Params { part_1_rocks: 100, cave_width: 9, ..Params::default() };
+
+        assert_eq!(solve_part_1(&jets, &params), Ok(reference::simulate(&jets, 100, 9)));
+
+        let params = Params { cave_width: 5, ..Params::default() };
+        assert!(solve_part_1(&jets, &params).is_err());
+    }
+
+    #[test]
+    fn test_animate() {
+        let mut animation = Animation::headless(Vec::new()).every(1000);
+        let sol = Day17::animate(&parse_input(INPUT).unwrap(), 1, &mut animation);
+
+        assert_eq!(sol, Ok(Answer::Integer(3068)));
+
+        let frames = String::from_utf8(animation.finish().unwrap()).unwrap();
+        assert!(frames.starts_with("Frame 0\n|...####|\n|.......|\n|.......|\n+-------+\n"));
+        assert!(frames
+            .split("Frame ")
+            .all(|frame| frame.lines().count() <= 1 + 24 + 1));
+
+        assert!(Day17::animate(&parse_input(INPUT).unwrap(), 3, &mut NoFrames).is_err());
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..5 {
+            let jets = parse_input(&aoc_generate::generate::<Day17>(seed, 40)).unwrap();
+
+            assert_eq!(jets.len(), 40);
+            assert!(solve_part_1(&jets, &Params::default()).unwrap() < solve_part_2(&jets, &Params::default()).unwrap());
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn test_reference(seed: u64, size in 1..=40usize, num_rocks in 0..=1000usize, width in 6..=10usize) {
+            let jets = parse_input(&aoc_generate::generate::<Day17>(seed, size)).unwrap();
 
-        assert_eq!(sol, 1_514_285_714_288);
+            prop_assert_eq!(
+                simulate(&jets, num_rocks, width, &mut NoFrames),
+                Ok(reference::simulate(&jets, num_rocks, width))
+            );
+        }
     }
 }
@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+
+use aoc_geometry::Direction;
+
+use crate::Point;
+
+const SHAPES: [&[(i64, i64)]; 5] = [
+    &[(0, 0), (1, 0), (2, 0), (3, 0)],
+    &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+    &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+    &[(0, 0), (0, 1), (0, 2), (0, 3)],
+    &[(0, 0), (1, 0), (0, 1), (1, 1)],
+];
+
+/// Drops `num_rocks` rocks one by one into a cave `width` tiles wide, keeping every tile of the
+/// stack and checking each move against all of them.
+pub fn simulate(jets: &[Direction], num_rocks: usize, width: usize) -> i64 {
+    let mut stack: HashSet<Point> = HashSet::new();
+    let mut height = 0;
+    let mut jets = jets.iter().cycle();
+
+    for shape in SHAPES.iter().cycle().take(num_rocks) {
+        let mut position = Point::new(2, height + 3);
+        let fits = |position: Point| {
+            shape.iter().all(|&(dx, dy)| {
+                let tile = position + Point::new(dx, dy);
+                (0..width as i64).contains(&tile.x) && tile.y >= 0 && !stack.contains(&tile)
+            })
+        };
+
+        loop {
+            let jet = *jets.next().expect("No jets");
+            if fits(position + jet.delta()) {
+                position += jet.delta();
+            }
+
+            let below = position + Direction::Down.delta();
+            if !fits(below) {
+                break;
+            }
+            position = below;
+        }
+
+        for &(dx, dy) in shape.iter() {
+            let tile = position + Point::new(dx, dy);
+            height = height.max(tile.y + 1);
+            stack.insert(tile);
+        }
+    }
+
+    height
+}
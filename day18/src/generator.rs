@@ -0,0 +1,39 @@
+use std::collections::HashSet;
+
+use aoc_generate::{rand::seq::IndexedRandom, Generated, InputRng};
+
+use crate::{Day18, Point};
+
+/// Largest coordinate of a cube, which keeps droplets about as big as the puzzle's.
+const MAX_COORDINATE: i32 = 21;
+
+impl Generated for Day18 {
+    /// Lava droplet of `size` cubes, grown from a single cube in the middle.
+    ///
+    /// Each cube is added next to a random cube already in the droplet, which makes for a lumpy
+    /// shape with the odd pocket of air.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        let side = MAX_COORDINATE as usize + 1;
+        let count = size.clamp(1, side.pow(3));
+
+        let middle = MAX_COORDINATE / 2;
+        let mut droplet = vec![Point::new(middle, middle, middle)];
+        let mut cubes: HashSet<Point> = droplet.iter().copied().collect();
+        while droplet.len() < count {
+            let cube = droplet.choose(rng).unwrap();
+            let next = *cube.neighbours_6().choose(rng).unwrap();
+
+            let inside = [next.x, next.y, next.z]
+                .iter()
+                .all(|c| (0..=MAX_COORDINATE).contains(c));
+            if inside && cubes.insert(next) {
+                droplet.push(next);
+            }
+        }
+
+        droplet
+            .iter()
+            .map(|cube| format!("{},{},{}\n", cube.x, cube.y, cube.z))
+            .collect()
+    }
+}
@@ -1,13 +1,28 @@
-use std::fs;
+use std::process;
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day18::*;
 
 fn main() {
-    let contents = fs::read_to_string("day18/input.txt").expect("Failed to read puzzle input");
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).expect("Failed to read puzzle input");
+    let input = Day18::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day18::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    match Day18::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 }
 
 #[cfg(test)]
@@ -30,15 +45,31 @@ mod tests {
 
     #[test]
     fn test_part_1() {
-        let sol = solve_part_1(INPUT);
+        let sol = solve_part_1(&parser::parse_input(INPUT).unwrap());
 
         assert_eq!(sol, 64);
     }
 
     #[test]
     fn test_part_2() {
-        let sol = solve_part_2(INPUT);
+        let sol = solve_part_2(&parser::parse_input(INPUT).unwrap());
 
         assert_eq!(sol, 58);
     }
+
+    #[test]
+    fn test_empty_droplet() {
+        assert_eq!(solve_part_1(&[]), 0);
+        assert_eq!(solve_part_2(&[]), 0);
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let droplet = parser::parse_input(&aoc_generate::generate::<Day18>(seed, 200)).unwrap();
+
+            assert_eq!(droplet.len(), 200);
+            assert!(solve_part_2(&droplet) <= solve_part_1(&droplet));
+        }
+    }
 }
@@ -1,30 +1,30 @@
+use aoc_core::ParseError;
+use aoc_parse::{lines, parse_all, signed, triple_of};
 use nom::{
-    character::complete::{char, digit1, line_ending},
-    combinator::{map, map_res, opt, recognize},
-    sequence::{delimited, pair, tuple},
-    IResult, multi::separated_list0,
+    combinator::{map, verify},
+    IResult,
 };
 
 use crate::*;
 
-pub(crate) fn parse_input(input: &str) -> Vec<Point> {
-    let (_, droplet) = droplet(input).expect("Failed to parse puzzle input");
-    droplet
+pub fn parse_input(input: &str) -> Result<Vec<Point>, ParseError> {
+    parse_all(input, droplet, "cube position like `1,2,3`")
 }
 
-fn integer(input: &str) -> IResult<&str, i32> {
-    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+/// Largest distance from the origin accepted, which keeps the space around the droplet small
+/// enough to flood.
+const MAX_COORDINATE: i32 = 50;
+
+fn coordinate(input: &str) -> IResult<&str, i32> {
+    verify(signed, |value: &i32| (-MAX_COORDINATE..=MAX_COORDINATE).contains(value))(input)
 }
 
 fn point(input: &str) -> IResult<&str, Point> {
-    map(
-        tuple((integer, delimited(char(','), integer, char(',')), integer)),
-        |(x, y, z)| Point::new(x, y, z),
-    )(input)
+    map(triple_of(coordinate, ","), |(x, y, z)| Point::new(x, y, z))(input)
 }
 
 fn droplet(input: &str) -> IResult<&str, Vec<Point>> {
-    separated_list0(line_ending, point)(input)
+    lines(point)(input)
 }
 
 #[cfg(test)]
@@ -35,7 +35,7 @@ mod tests {
     fn test_integer() {
         let input = "-123";
 
-        let (rem, int) = integer(input).unwrap();
+        let (rem, int) = signed::<i32>(input).unwrap();
 
         assert_eq!(int, -123);
         assert!(rem.is_empty());
@@ -51,6 +51,16 @@ mod tests {
         assert!(rem.is_empty());
     }
 
+    #[test]
+    fn test_point_too_far() {
+        assert!(point("1,-51,3").is_err());
+    }
+
+    #[test]
+    fn test_min_coordinate() {
+        assert!(parse_input("1,-2147483648,3").is_err());
+    }
+
     #[test]
     fn test_droplet() {
         let input = "1,2,3
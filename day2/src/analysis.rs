@@ -0,0 +1,73 @@
+use aoc_core::SolveError;
+
+use crate::{Game, Round};
+
+/// Expected scores of responses to the opponent's shapes in a strategy guide.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    /// Share of rounds in which the opponent throws each shape of the game.
+    pub opponent: Vec<f64>,
+    /// Expected score per round of always throwing each shape.
+    pub fixed: Vec<f64>,
+    /// Shape with the highest expected score, the first one if several tie.
+    ///
+    /// No mixed response does better, since its expected score is a weighted mean of these.
+    pub best: usize,
+}
+
+impl Analysis {
+    /// Analyses responses to the opponent's shapes in `strat`, ignoring the second column.
+    pub fn new(strat: &[Round], game: &Game) -> Result<Self, SolveError> {
+        if strat.is_empty() {
+            return Err(SolveError::new("No rounds in the strategy guide"));
+        }
+
+        let mut counts = vec![0usize; game.shapes().len()];
+        for round in strat {
+            counts[game.opponent_shape(round.opponent)?] += 1;
+        }
+
+        let rounds = strat.len() as f64;
+        let opponent = counts.iter().map(|&count| count as f64 / rounds).collect();
+        let fixed: Vec<f64> = (0..game.shapes().len())
+            .map(|player| {
+                let total: i64 = counts
+                    .iter()
+                    .enumerate()
+                    .map(|(shape, &count)| count as i64 * i64::from(game.score(shape, player)))
+                    .sum();
+                total as f64 / rounds
+            })
+            .collect();
+
+        let best = (0..fixed.len())
+            .reduce(|best, shape| if fixed[shape] > fixed[best] { shape } else { best })
+            .expect("Games have at least one shape");
+
+        Ok(Self { opponent, fixed, best })
+    }
+
+    /// Expected score per round of the best response.
+    pub fn best_score(&self) -> f64 {
+        self.fixed[self.best]
+    }
+
+    /// Expected score per round of throwing each shape with the chance given by `weights`.
+    pub fn mixed(&self, weights: &[f64]) -> Result<f64, SolveError> {
+        check_weights(weights, self.fixed.len())?;
+
+        Ok(weights.iter().zip(&self.fixed).map(|(weight, score)| weight * score).sum())
+    }
+}
+
+/// Fails unless `weights` give a chance for each of `shapes` and add up to 1.
+pub(crate) fn check_weights(weights: &[f64], shapes: usize) -> Result<(), SolveError> {
+    if weights.len() != shapes {
+        return Err(SolveError::new(format!("Need a weight for each of the {shapes} shapes, not {}", weights.len())));
+    }
+    if weights.iter().any(|weight| !(0.0..=1.0).contains(weight)) || (weights.iter().sum::<f64>() - 1.0).abs() > 1e-9 {
+        return Err(SolveError::new("Weights must be between 0 and 1 and add up to 1"));
+    }
+
+    Ok(())
+}
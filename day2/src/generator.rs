@@ -0,0 +1,16 @@
+use aoc_generate::{rand::seq::IndexedRandom, Generated, InputRng};
+
+use crate::Day2;
+
+impl Generated for Day2 {
+    /// `size` rounds of the strategy guide.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let opponent = ['A', 'B', 'C'].choose(rng).unwrap();
+                let response = ['X', 'Y', 'Z'].choose(rng).unwrap();
+                format!("{opponent} {response}\n")
+            })
+            .collect()
+    }
+}
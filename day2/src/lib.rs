@@ -1,129 +1,258 @@
-use std::{str::FromStr, cmp::Ordering};
+use std::collections::HashSet;
 
-#[derive(PartialEq, Eq, Clone, Copy)]
-enum Outcome {
-    Win,
-    Draw,
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+
+/// Expected scores of responses to the opponent's shapes in a strategy guide.
+pub mod analysis;
+mod generator;
+/// Round-robin tournaments between players following different strategies.
+pub mod tournament;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input<'a> = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input, &Game::default())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input, &Game::default())?.into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input, &Game::default())?.into())
+    }
+}
+
+/// Line of the strategy guide, with the letters of both columns as written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    opponent: char,
+    response: char,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Outcome {
     Lose,
+    Draw,
+    Win,
 }
 
-impl FromStr for Outcome {
-    type Err = &'static str;
+/// Shape a player can throw, with the letters standing for it in each column of the strategy guide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shape {
+    pub name: String,
+    /// Letter of the shape in the first column, thrown by the opponent.
+    pub opponent: char,
+    /// Letter of the shape in the second column, when it is read as our shape in part 1.
+    pub response: char,
+    /// Score for throwing the shape, whatever the outcome.
+    pub score: i32,
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "X" => Ok(Self::Lose),
-            "Y" => Ok(Self::Draw),
-            "Z" => Ok(Self::Win),
-            _ => Err("Invalid move encountered")
+impl Shape {
+    pub fn new(name: &str, opponent: char, response: char, score: i32) -> Self {
+        Self {
+            name: name.to_string(),
+            opponent,
+            response,
+            score,
         }
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Copy)]
-enum Move {
-    Rock,
-    Paper,
-    Scissors,
+/// Cyclic hand game, in which each shape beats the half of the other shapes right before it,
+/// wrapping around. Needs an odd number of at least 3 shapes, so that every pair of distinct shapes
+/// has a winner and every shape can be beaten.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    shapes: Vec<Shape>,
+    /// Letters of the second column standing for losing, drawing and winning in part 2.
+    outcome_letters: [char; 3],
+    /// Scores for losing, drawing and winning a round.
+    outcome_scores: [i32; 3],
 }
 
-impl Move {
-    fn score(&self) -> i32 {
-        match self {
-            Move::Rock => 1,
-            Move::Paper => 2,
-            Move::Scissors => 3,
-        }
+impl Default for Game {
+    fn default() -> Self {
+        Self::rock_paper_scissors()
     }
+}
 
-    fn cheat(&self, outcome: Outcome) -> Self {
-        use Move::*;
-        use Outcome::*;
-
-        match (self, outcome) {
-            (Rock, Win) => Paper,
-            (Rock, Draw) => Rock,
-            (Rock, Lose) => Scissors,
-            (Paper, Win) => Scissors,
-            (Paper, Draw) => Paper,
-            (Paper, Lose) => Rock,
-            (Scissors, Win) => Rock,
-            (Scissors, Draw) => Scissors,
-            (Scissors, Lose) => Paper,
+impl Game {
+    /// Game of `shapes`, given in their cyclic order, fails unless there is an odd number of at
+    /// least 3 of them and letters of the same column all differ.
+    pub fn new(shapes: Vec<Shape>, outcome_letters: [char; 3], outcome_scores: [i32; 3]) -> Result<Self, SolveError> {
+        // With a single shape every round is a draw, so there is no way to win or lose.
+        if shapes.len() < 3 {
+            return Err(SolveError::new(format!("Game needs at least 3 shapes, not {}", shapes.len())));
         }
+        if shapes.len().is_multiple_of(2) {
+            return Err(SolveError::new(format!("Game needs an odd number of shapes, not {}", shapes.len())));
+        }
+
+        if !all_differ(shapes.iter().map(|shape| shape.opponent))
+            || !all_differ(shapes.iter().map(|shape| shape.response))
+            || !all_differ(outcome_letters)
+        {
+            return Err(SolveError::new("Letters of the same column stand for more than one thing"));
+        }
+
+        Ok(Self {
+            shapes,
+            outcome_letters,
+            outcome_scores,
+        })
     }
-}
 
-impl FromStr for Move {
-    type Err = &'static str;
+    /// The game of the puzzle.
+    pub fn rock_paper_scissors() -> Self {
+        Self::new(
+            vec![
+                Shape::new("Rock", 'A', 'X', 1),
+                Shape::new("Paper", 'B', 'Y', 2),
+                Shape::new("Scissors", 'C', 'Z', 3),
+            ],
+            ['X', 'Y', 'Z'],
+            [0, 3, 6],
+        )
+        .expect("Rock paper scissors is a valid game")
+    }
+
+    /// Rock paper scissors with lizard (`D` and `V`) and Spock (`E` and `W`) added, in which
+    /// rock, paper and scissors still beat each other the same way.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::new(
+            vec![
+                Shape::new("Rock", 'A', 'X', 1),
+                Shape::new("Spock", 'E', 'W', 5),
+                Shape::new("Paper", 'B', 'Y', 2),
+                Shape::new("Lizard", 'D', 'V', 4),
+                Shape::new("Scissors", 'C', 'Z', 3),
+            ],
+            ['X', 'Y', 'Z'],
+            [0, 3, 6],
+        )
+        .expect("Rock paper scissors lizard Spock is a valid game")
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "A" | "X" => Ok(Move::Rock),
-            "B" | "Y" => Ok(Move::Paper),
-            "C" | "Z" => Ok(Move::Scissors),
-            _ => Err("Invalid move encountered")
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+
+    /// Outcome for the player throwing shape `player` against shape `opponent`.
+    pub fn outcome(&self, opponent: usize, player: usize) -> Outcome {
+        let n = self.shapes.len();
+        let ahead = (player + n - opponent) % n;
+
+        if ahead == 0 {
+            Outcome::Draw
+        } else if ahead <= n / 2 {
+            Outcome::Win
+        } else {
+            Outcome::Lose
         }
     }
-}
 
-impl PartialOrd for Move {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        use Move::*;
+    /// Score of the player throwing shape `player` against shape `opponent`.
+    pub fn score(&self, opponent: usize, player: usize) -> i32 {
+        self.shapes[player].score + self.outcome_scores[self.outcome(opponent, player) as usize]
+    }
+
+    /// Shape to throw against shape `opponent` to get `outcome`, the one closest to it if there are several.
+    pub fn cheat(&self, opponent: usize, outcome: Outcome) -> usize {
+        let n = self.shapes.len();
 
-        match (self, other) {
-            (Rock, Scissors) | (Paper, Rock) | (Scissors, Paper) => Some(Ordering::Greater),
-            (Scissors, Rock) | (Rock, Paper) | (Paper, Scissors) => Some(Ordering::Less),
-            _ => Some(Ordering::Equal)
+        match outcome {
+            Outcome::Lose => (opponent + n - 1) % n,
+            Outcome::Draw => opponent,
+            Outcome::Win => (opponent + 1) % n,
         }
     }
-}
 
-fn score(opponent: Move, player: Move) -> i32 {
-    let mut score = player.score();
+    fn opponent_shape(&self, letter: char) -> Result<usize, SolveError> {
+        self.shapes
+            .iter()
+            .position(|shape| shape.opponent == letter)
+            .ok_or_else(|| SolveError::new(format!("Opponent's {letter} is no shape of the game")))
+    }
 
-    if opponent == player {
-        score += 3;
-    } else if opponent < player {
-        score += 6;
+    fn response_shape(&self, letter: char) -> Result<usize, SolveError> {
+        self.shapes
+            .iter()
+            .position(|shape| shape.response == letter)
+            .ok_or_else(|| SolveError::new(format!("Response {letter} is no shape of the game")))
     }
 
-    score
+    fn response_outcome(&self, letter: char) -> Result<Outcome, SolveError> {
+        match self.outcome_letters.iter().position(|&outcome| outcome == letter) {
+            Some(0) => Ok(Outcome::Lose),
+            Some(1) => Ok(Outcome::Draw),
+            Some(2) => Ok(Outcome::Win),
+            _ => Err(SolveError::new(format!("Response {letter} is no outcome of the game"))),
+        }
+    }
 }
 
-pub fn solve_part_1(input: &str) -> i32 {
-    let strat: Vec<(Move, Move)> = input.lines()
-        .map(|line| {
-            let mut split = line.split(' ');
-            let opponent = split.next().unwrap();
-            let player = split.next().unwrap();
-            (opponent.parse().unwrap(), player.parse().unwrap())
-        })
-        .collect();
+fn all_differ(letters: impl IntoIterator<Item = char>) -> bool {
+    let mut seen = HashSet::new();
+    letters.into_iter().all(|letter| seen.insert(letter))
+}
 
-    let final_score = strat.iter()
-        .map(|(opponent, player)| score(*opponent, *player))
-        .sum();
+/// Lists `letters` in order, as in "X, Y or Z".
+fn list_letters(letters: &HashSet<char>) -> String {
+    let mut letters: Vec<_> = letters.iter().map(char::to_string).collect();
+    letters.sort_unstable();
 
-    final_score
+    match letters.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} or {last}", rest.join(", ")),
+        _ => letters.concat(),
+    }
 }
 
-pub fn solve_part_2(input: &str) -> i32 {
-    let strat: Vec<(Move, Outcome)> = input.lines()
+/// Reads the strategy guide, whose letters must all stand for something in `game`.
+pub fn parse_input(input: &str, game: &Game) -> Result<Vec<Round>, ParseError> {
+    let opponents: HashSet<char> = game.shapes.iter().map(|shape| shape.opponent).collect();
+    let responses: HashSet<char> = game.shapes.iter().map(|shape| shape.response).chain(game.outcome_letters).collect();
+
+    let letter = |column: &str, letters: &HashSet<char>, expected: &str| {
+        let mut chars = column.chars();
+        match (chars.next(), chars.next()) {
+            (Some(letter), None) if letters.contains(&letter) => Ok(letter),
+            _ => Err(ParseError::new(input, column, format!("{expected} ({})", list_letters(letters)))),
+        }
+    };
+
+    input.lines()
         .map(|line| {
-            let mut split = line.split(' ');
-            let opponent = split.next().unwrap();
-            let outcome = split.next().unwrap();
-            (opponent.parse().unwrap(), outcome.parse().unwrap())
-        })
-        .collect();
+            let (opponent, response) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(input, line, "two columns separated by a space"))?;
 
-    let final_score = strat.iter()
-        .map(|(opponent, outcome)| {
-            let player = opponent.cheat(*outcome);
-            score(*opponent, player)
+            Ok(Round {
+                opponent: letter(opponent, &opponents, "opponent move")?,
+                response: letter(response, &responses, "response")?,
+            })
         })
-        .sum();
+        .collect()
+}
 
-    final_score
+/// Total score when the second column is the shape to throw.
+pub fn solve_part_1(strat: &[Round], game: &Game) -> Result<i32, SolveError> {
+    strat.iter()
+        .map(|round| Ok(game.score(game.opponent_shape(round.opponent)?, game.response_shape(round.response)?)))
+        .sum()
+}
+
+/// Total score when the second column is the outcome to get.
+pub fn solve_part_2(strat: &[Round], game: &Game) -> Result<i32, SolveError> {
+    strat.iter()
+        .map(|round| {
+            let opponent = game.opponent_shape(round.opponent)?;
+            let player = game.cheat(opponent, game.response_outcome(round.response)?);
+            Ok(game.score(opponent, player))
+        })
+        .sum()
 }
@@ -1,19 +1,39 @@
-use std::{error, fs};
+use std::{error, process};
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day2::*;
 
 fn main() -> Result<(), Box<dyn error::Error>> {
-    let contents = fs::read_to_string("day2/input.txt")?;
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"))?;
+    let input = Day2::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day2::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    match Day2::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    use day2::{
+        analysis::Analysis,
+        tournament::{Player, Strategy, Tournament},
+    };
+
     use super::*;
 
     #[test]
@@ -22,8 +42,8 @@ mod tests {
 B X
 C Z";
 
-        let sol = solve_part_1(input);
-        assert_eq!(sol, 15)
+        let sol = solve_part_1(&parse_input(input, &Game::default()).unwrap(), &Game::default());
+        assert_eq!(sol, Ok(15))
     }
 
     #[test]
@@ -32,7 +52,142 @@ C Z";
 B X
 C Z";
 
-        let sol = solve_part_2(input);
-        assert_eq!(sol, 12)
+        let sol = solve_part_2(&parse_input(input, &Game::default()).unwrap(), &Game::default());
+        assert_eq!(sol, Ok(12))
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let rounds = parse_input(&aoc_generate::generate::<Day2>(seed, 20), &Game::default()).unwrap();
+
+            assert_eq!(rounds.len(), 20);
+            assert!((20..=180).contains(&solve_part_1(&rounds, &Game::default()).unwrap()));
+            assert!((20..=180).contains(&solve_part_2(&rounds, &Game::default()).unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_lizard_spock() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let shape = |name| game.shapes().iter().position(|shape| shape.name == name).unwrap();
+
+        for (winner, loser) in [
+            ("Scissors", "Paper"), ("Paper", "Rock"), ("Rock", "Lizard"), ("Lizard", "Spock"), ("Spock", "Scissors"),
+            ("Scissors", "Lizard"), ("Lizard", "Paper"), ("Paper", "Spock"), ("Spock", "Rock"), ("Rock", "Scissors"),
+        ] {
+            assert_eq!(game.outcome(shape(loser), shape(winner)), Outcome::Win, "{winner} beats {loser}");
+            assert_eq!(game.outcome(shape(winner), shape(loser)), Outcome::Lose, "{loser} loses to {winner}");
+        }
+
+        let input = "A Y\nB X\nC Z";
+        let rounds = parse_input(input, &game).unwrap();
+        assert_eq!(solve_part_1(&rounds, &game), Ok(15));
+        // Losing to paper and beating scissors are done with Spock and rock instead.
+        assert_eq!(solve_part_2(&rounds, &game), Ok(16));
+
+        let rounds = parse_input("E V\nD X", &game).unwrap();
+        assert_eq!(solve_part_1(&rounds, &game), Ok(17));
+        assert!(solve_part_2(&rounds, &game).is_err());
+        assert!(parse_input("E V", &Game::default()).is_err());
+    }
+
+    #[test]
+    fn test_cheat() {
+        let seven = (0..7)
+            .map(|i| Shape::new(&i.to_string(), char::from(b'A' + i), char::from(b'R' + i), i32::from(i)))
+            .collect();
+        let games = [Game::default(), Game::rock_paper_scissors_lizard_spock(), Game::new(seven, ['R', 'S', 'T'], [0, 3, 6]).unwrap()];
+
+        for game in games {
+            for opponent in 0..game.shapes().len() {
+                for outcome in [Outcome::Lose, Outcome::Draw, Outcome::Win] {
+                    assert_eq!(game.outcome(opponent, game.cheat(opponent, outcome)), outcome);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_game() {
+        let shapes = Game::default().shapes().to_vec();
+
+        assert!(Game::new(shapes[..2].to_vec(), ['X', 'Y', 'Z'], [0, 3, 6]).is_err());
+        assert!(Game::new(shapes[..1].to_vec(), ['X', 'Y', 'Z'], [0, 3, 6]).is_err());
+        assert!(Game::new(vec![], ['X', 'Y', 'Z'], [0, 3, 6]).is_err());
+        assert!(Game::new(shapes.clone(), ['X', 'X', 'Z'], [0, 3, 6]).is_err());
+
+        let mut shapes = shapes;
+        shapes[2].opponent = 'A';
+        assert!(Game::new(shapes, ['X', 'Y', 'Z'], [0, 3, 6]).is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("A Y\nD X", &Game::default()).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "opponent move (A, B or C)");
+        assert_eq!(parse_input("A XY", &Game::default()).unwrap_err().expected, "response (X, Y or Z)");
+    }
+
+    #[test]
+    fn test_analysis() {
+        let game = Game::default();
+        let analysis = Analysis::new(&parse_input("A Y\nB X\nC Z", &game).unwrap(), &game).unwrap();
+
+        assert_eq!(analysis.fixed, [4.0, 5.0, 6.0]);
+        assert_eq!((analysis.best, analysis.best_score()), (2, 6.0));
+        assert_eq!(analysis.mixed(&[0.5, 0.0, 0.5]), Ok(5.0));
+        assert!(analysis.mixed(&[0.5, 0.5]).is_err());
+        assert!(analysis.mixed(&[0.5, 0.6, -0.1]).is_err());
+
+        let analysis = Analysis::new(&parse_input("A X\nA Z\nA Y\nC X", &game).unwrap(), &game).unwrap();
+        assert_eq!(analysis.opponent, [0.75, 0.0, 0.25]);
+        assert_eq!((analysis.best, analysis.best_score()), (1, 6.5));
+        assert!(Analysis::new(&[], &game).is_err());
+    }
+
+    #[test]
+    fn test_tournament() {
+        let game = Game::default();
+        let guide = Strategy::from_guide(&parse_input("A Y\nB X\nC Z", &game).unwrap(), &game).unwrap();
+        let players = vec![
+            Player::new("Rocky", Strategy::Fixed(0)),
+            Player::new("Paige", Strategy::Fixed(1)),
+            Player::new("Guide", guide),
+            Player::new("Copycat", Strategy::BeatLast),
+        ];
+        let tournament = Tournament::round_robin(&game, players, 10, 0).unwrap();
+
+        assert_eq!(tournament.matches.len(), 6);
+        assert_eq!(tournament.matches[0].players, [0, 1]);
+        assert_eq!(tournament.matches[0].totals, [10, 80]);
+        assert!(tournament.matches.iter().all(|played| played.rounds.len() == 10));
+
+        let standings: Vec<_> = tournament.standings.iter().map(|s| (s.player, s.wins, s.draws, s.losses)).collect();
+        assert_eq!(standings, [(2, 2, 1, 0), (3, 2, 0, 1), (1, 1, 1, 1), (0, 0, 0, 3)]);
+
+        let log = tournament.to_string();
+        assert!(log.starts_with("Rocky vs Paige\n  Round 1: Rock vs Paper, 1 to 8\n"));
+        assert!(log.contains("Rocky vs Copycat\n  Round 1: Rock vs Rock, 4 to 4\n  Round 2: Rock vs Paper, 1 to 8\n"));
+        assert!(log.contains("Standings:\n1. Guide: 2 won, 1 drawn, 0 lost, score 183\n"));
+    }
+
+    #[test]
+    fn test_mixed_tournament() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let players = || vec![
+            Player::new("Uniform", Strategy::Mixed(vec![0.2; 5])),
+            Player::new("Spock", Strategy::Mixed(vec![0.0, 1.0, 0.0, 0.0, 0.0])),
+        ];
+
+        let tournament = Tournament::round_robin(&game, players(), 50, 7).unwrap();
+        assert_eq!(tournament, Tournament::round_robin(&game, players(), 50, 7).unwrap());
+        assert!(tournament.matches[0].rounds.iter().all(|log| log.shapes[1] == 1));
+        assert!(tournament.matches[0].rounds.iter().any(|log| log.shapes[0] != tournament.matches[0].rounds[0].shapes[0]));
+
+        assert!(Tournament::round_robin(&game, vec![Player::new("Lost", Strategy::Fixed(5))], 1, 0).is_err());
+        assert!(Tournament::round_robin(&game, vec![Player::new("Idle", Strategy::Sequence(vec![]))], 1, 0).is_err());
     }
 }
@@ -0,0 +1,230 @@
+use std::fmt::Display;
+
+use aoc_core::SolveError;
+use aoc_generate::{
+    rand::{RngExt, SeedableRng},
+    InputRng,
+};
+
+use crate::{analysis::check_weights, Game, Outcome, Round};
+
+/// Way a player picks its shape each round.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Strategy {
+    /// Always throws the same shape.
+    Fixed(usize),
+    /// Throws each shape with the chance given by its weight.
+    Mixed(Vec<f64>),
+    /// Throws the shapes in order, starting over at the end, as when following a strategy guide.
+    Sequence(Vec<usize>),
+    /// Throws the shape after the one the opponent threw last, which would have beaten it.
+    BeatLast,
+}
+
+impl Strategy {
+    /// Throws the shapes of the second column of `strat` in order, reading it as in part 1.
+    pub fn from_guide(strat: &[Round], game: &Game) -> Result<Self, SolveError> {
+        let sequence = strat.iter().map(|round| game.response_shape(round.response)).collect::<Result<_, _>>()?;
+        Ok(Strategy::Sequence(sequence))
+    }
+
+    fn check(&self, game: &Game) -> Result<(), SolveError> {
+        let shapes = game.shapes().len();
+        match self {
+            Strategy::Fixed(shape) if *shape >= shapes => Err(SolveError::new(format!("No shape {shape} in the game"))),
+            Strategy::Mixed(weights) => check_weights(weights, shapes),
+            Strategy::Sequence(sequence) if sequence.is_empty() => Err(SolveError::new("Sequence of shapes is empty")),
+            Strategy::Sequence(sequence) => match sequence.iter().find(|&&shape| shape >= shapes) {
+                Some(shape) => Err(SolveError::new(format!("No shape {shape} in the game"))),
+                None => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Shape to throw in `round`, starting at 0, after the opponent last threw `last`.
+    fn pick(&self, game: &Game, round: usize, last: Option<usize>, rng: &mut InputRng) -> usize {
+        match self {
+            Strategy::Fixed(shape) => *shape,
+            Strategy::Mixed(weights) => {
+                let mut roll: f64 = rng.random();
+                weights
+                    .iter()
+                    .position(|weight| {
+                        roll -= weight;
+                        roll < 0.0
+                    })
+                    .unwrap_or_else(|| weights.iter().rposition(|&weight| weight > 0.0).unwrap_or(0))
+            }
+            Strategy::Sequence(sequence) => sequence[round % sequence.len()],
+            Strategy::BeatLast => last.map_or(0, |last| game.cheat(last, Outcome::Win)),
+        }
+    }
+}
+
+/// Player entering a tournament.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Player {
+    pub name: String,
+    pub strategy: Strategy,
+}
+
+impl Player {
+    pub fn new(name: &str, strategy: Strategy) -> Self {
+        Self {
+            name: name.to_string(),
+            strategy,
+        }
+    }
+}
+
+/// Shapes thrown and scores of both players in a single round of a match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundLog {
+    pub shapes: [usize; 2],
+    pub scores: [i32; 2],
+}
+
+/// Match between two players, given by their positions in the tournament.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    pub players: [usize; 2],
+    pub rounds: Vec<RoundLog>,
+    pub totals: [i64; 2],
+}
+
+impl Match {
+    /// Outcome of the match for its first player.
+    pub fn outcome(&self) -> Outcome {
+        match self.totals[0].cmp(&self.totals[1]) {
+            std::cmp::Ordering::Less => Outcome::Lose,
+            std::cmp::Ordering::Equal => Outcome::Draw,
+            std::cmp::Ordering::Greater => Outcome::Win,
+        }
+    }
+}
+
+/// Results of a single player over all of its matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Standing {
+    pub player: usize,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub score: i64,
+}
+
+/// Round-robin tournament, in which every player plays a match against every other player.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tournament {
+    shape_names: Vec<String>,
+    pub players: Vec<Player>,
+    pub matches: Vec<Match>,
+    /// Standings of all players, the most wins first and then the highest score.
+    pub standings: Vec<Standing>,
+}
+
+impl Tournament {
+    /// Plays `rounds` rounds of `game` between each pair of `players`, with the same results
+    /// for the same `seed`.
+    pub fn round_robin(game: &Game, players: Vec<Player>, rounds: usize, seed: u64) -> Result<Self, SolveError> {
+        for player in &players {
+            player.strategy.check(game)?;
+        }
+
+        let mut rng = InputRng::seed_from_u64(seed);
+        let mut standings: Vec<Standing> = (0..players.len()).map(|player| Standing { player, ..Standing::default() }).collect();
+        let mut matches = Vec::new();
+        for first in 0..players.len() {
+            for second in first + 1..players.len() {
+                let played = play(game, &players, [first, second], rounds, &mut rng);
+
+                for (player, outcome) in [(first, played.outcome()), (second, flip(played.outcome()))] {
+                    let standing = &mut standings[player];
+                    match outcome {
+                        Outcome::Win => standing.wins += 1,
+                        Outcome::Draw => standing.draws += 1,
+                        Outcome::Lose => standing.losses += 1,
+                    }
+                }
+                standings[first].score += played.totals[0];
+                standings[second].score += played.totals[1];
+
+                matches.push(played);
+            }
+        }
+
+        standings.sort_by(|a, b| b.wins.cmp(&a.wins).then(b.score.cmp(&a.score)));
+
+        Ok(Self {
+            shape_names: game.shapes().iter().map(|shape| shape.name.clone()).collect(),
+            players,
+            matches,
+            standings,
+        })
+    }
+}
+
+fn play(game: &Game, players: &[Player], pair: [usize; 2], rounds: usize, rng: &mut InputRng) -> Match {
+    let strategies = pair.map(|player| &players[player].strategy);
+
+    let mut played = Match { players: pair, rounds: Vec::with_capacity(rounds), totals: [0, 0] };
+    for round in 0..rounds {
+        let last = played.rounds.last();
+        let shapes = [
+            strategies[0].pick(game, round, last.map(|log| log.shapes[1]), rng),
+            strategies[1].pick(game, round, last.map(|log| log.shapes[0]), rng),
+        ];
+        let scores = [game.score(shapes[1], shapes[0]), game.score(shapes[0], shapes[1])];
+
+        played.totals[0] += i64::from(scores[0]);
+        played.totals[1] += i64::from(scores[1]);
+        played.rounds.push(RoundLog { shapes, scores });
+    }
+
+    played
+}
+
+fn flip(outcome: Outcome) -> Outcome {
+    match outcome {
+        Outcome::Lose => Outcome::Win,
+        Outcome::Draw => Outcome::Draw,
+        Outcome::Win => Outcome::Lose,
+    }
+}
+
+impl Display for Tournament {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for played in &self.matches {
+            let [first, second] = played.players.map(|player| &self.players[player].name);
+            writeln!(f, "{first} vs {second}")?;
+            for (round, log) in played.rounds.iter().enumerate() {
+                let [first_shape, second_shape] = log.shapes.map(|shape| &self.shape_names[shape]);
+                writeln!(
+                    f,
+                    "  Round {}: {first_shape} vs {second_shape}, {} to {}",
+                    round + 1,
+                    log.scores[0],
+                    log.scores[1]
+                )?;
+            }
+            writeln!(f, "  Total: {} to {}", played.totals[0], played.totals[1])?;
+        }
+
+        writeln!(f, "Standings:")?;
+        for (place, standing) in self.standings.iter().enumerate() {
+            writeln!(
+                f,
+                "{}. {}: {} won, {} drawn, {} lost, score {}",
+                place + 1,
+                self.players[standing.player].name,
+                standing.wins,
+                standing.draws,
+                standing.losses,
+                standing.score
+            )?;
+        }
+
+        Ok(())
+    }
+}
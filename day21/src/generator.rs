@@ -0,0 +1,143 @@
+use aoc_generate::{
+    rand::{seq::SliceRandom, RngExt},
+    Generated, InputRng, LOWERCASE,
+};
+
+use crate::Day21;
+
+/// Values above which no more divisions are nested, to keep clear of overflows.
+const MAX_VALUE: i64 = 1_000_000_000;
+
+/// Number of different four letter names.
+const NAMES: usize = 26 * 26 * 26 * 26;
+
+/// Step between the indices of consecutive names, which shares no factor with `NAMES` so that
+/// every name comes up once before any repeats.
+const NAME_STEP: usize = 7919;
+
+impl Generated for Day21 {
+    /// Expression tree of about `size` monkeys, with `humn` somewhere among its leaves. There are
+    /// only enough names for `NAMES - 1` monkeys, so larger sizes get that many.
+    ///
+    /// The tree is built from the top down, splitting a value between the two monkeys below each
+    /// operation so that every division is exact. Both sides of `root` get the same value for some
+    /// number that `humn` could yell instead of its own, which makes that number the answer to
+    /// part 2.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        let leaves = size.div_ceil(2).clamp(2, NAMES / 2);
+        let target = rng.random_range(1_000..=100_000);
+        let humn_leaf = rng.random_range(0..leaves);
+
+        let mut tree = Tree {
+            first_name: rng.random_range(0..NAMES),
+            named: 0,
+            rng,
+            jobs: Vec::new(),
+        };
+
+        let left_leaves = tree.rng.random_range(1..leaves);
+        let humn_left = humn_leaf < left_leaves;
+        let left = tree.monkey(target, left_leaves, humn_left.then_some(humn_leaf));
+        let right = tree.monkey(
+            target,
+            leaves - left_leaves,
+            (!humn_left).then(|| humn_leaf - left_leaves),
+        );
+        tree.jobs.push(format!("root: {left} + {right}"));
+
+        let mut jobs = tree.jobs;
+        jobs.shuffle(rng);
+
+        jobs.join("\n") + "\n"
+    }
+}
+
+struct Tree<'a> {
+    rng: &'a mut InputRng,
+    /// Index of the first name handed out, and the number handed out since.
+    first_name: usize,
+    named: usize,
+    jobs: Vec<String>,
+}
+
+impl Tree<'_> {
+    /// Adds a monkey that yells `value` from `leaves` numbers, the `humn`-th of which is the
+    /// human if given, and returns its name.
+    fn monkey(&mut self, value: i64, leaves: usize, humn: Option<usize>) -> String {
+        if leaves == 1 {
+            if humn.is_some() {
+                let yell = value + self.rng.random_range(1..=100);
+                self.jobs.push(format!("humn: {yell}"));
+                return "humn".to_string();
+            }
+
+            let name = self.name();
+            self.jobs.push(format!("{name}: {value}"));
+            return name;
+        }
+
+        let left_leaves = self.rng.random_range(1..leaves);
+        let humn_left = humn.map(|humn| humn < left_leaves);
+
+        let factor = [2, 3, 5, 7]
+            .into_iter()
+            .find(|factor| value > *factor && value % factor == 0);
+        let (operation, left_value, right_value) = match self.rng.random_range(0..4) {
+            0 if value > 1 => {
+                let left = self.rng.random_range(1..value);
+                ('+', left, value - left)
+            }
+            // The human's side of a division must be the dividend, or the division in part 2
+            // could end up dividing by zero.
+            1 if value < MAX_VALUE && humn_left != Some(false) => {
+                let divisor = self.rng.random_range(2..=5);
+                ('/', value * divisor, divisor)
+            }
+            // The human's side of a multiplication gets the larger factor, so that the human's own
+            // number only gets multiplied by small ones on the way up in part 1.
+            2 if factor.is_some() => {
+                let factor = factor.unwrap();
+                match humn_left {
+                    Some(true) => ('*', value / factor, factor),
+                    _ => ('*', factor, value / factor),
+                }
+            }
+            _ => {
+                let right = self.rng.random_range(1..=1000);
+                ('-', value + right, right)
+            }
+        };
+
+        let (left_humn, right_humn) = match (humn, humn_left) {
+            (Some(humn), Some(true)) => (Some(humn), None),
+            (Some(humn), Some(false)) => (None, Some(humn - left_leaves)),
+            _ => (None, None),
+        };
+        let left = self.monkey(left_value, left_leaves, left_humn);
+        let right = self.monkey(right_value, leaves - left_leaves, right_humn);
+
+        let name = self.name();
+        self.jobs
+            .push(format!("{name}: {left} {operation} {right}"));
+        name
+    }
+
+    /// Hands out the next name in a shuffled order, skipping `root` and `humn`.
+    fn name(&mut self) -> String {
+        loop {
+            let mut index = (self.first_name + self.named * NAME_STEP) % NAMES;
+            self.named += 1;
+
+            let name: String = (0..4)
+                .map(|_| {
+                    let letter = LOWERCASE[index % LOWERCASE.len()];
+                    index /= LOWERCASE.len();
+                    letter as char
+                })
+                .collect();
+            if name != "root" && name != "humn" {
+                return name;
+            }
+        }
+    }
+}
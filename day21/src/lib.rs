@@ -1,92 +1,152 @@
-mod parser;
+mod generator;
+pub mod parser;
 
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
 
-use parser::parse_input;
+use aoc_core::{Answer, ParseError, Solution, SolveError};
 
-pub fn solve_part_1(input: &str) -> i64 {
-    let monkeys = parse_input(input);
+pub struct Day21;
 
-    let monkeys = solve(monkeys);
+impl Solution for Day21 {
+    type Input<'a> = HashMap<&'a str, Monkey<'a>>;
 
-    monkeys["root"]
-        .yell
-        .value()
-        .expect("Failed to calculate root value")
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parser::parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input)?.into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input)?.into())
+    }
+}
+
+pub fn solve_part_1(monkeys: &HashMap<&str, Monkey>) -> Result<i64, SolveError> {
+    Ok(solve(monkeys)?["root"])
 }
 
-pub fn solve_part_2(input: &str) -> i64 {
-    let monkeys = parse_input(input);
+pub fn solve_part_2(monkeys: &HashMap<&str, Monkey>) -> Result<i64, SolveError> {
+    let values = solve(monkeys)?;
+    let value = |ident: &str| values[ident];
+    let dependents = humn_dependents(monkeys, &values);
 
-    let (left_ident, right_ident) = if let Yell::Operation { left, right, .. } = monkeys["root"].yell {
-        (left, right)
-    } else {
-        unreachable!();
+    let Yell::Operation { left: left_ident, right: right_ident, .. } = monkeys["root"].yell else {
+        return Err(SolveError::new("Monkey root yells a number instead of comparing two others"));
+    };
+
+    // Walk down from root towards humn, undoing each operation on the way.
+    let (mut ident, mut target) = match humn_side(&dependents, "root", left_ident, right_ident)? {
+        Side::Left => (left_ident, value(right_ident)),
+        Side::Right => (right_ident, value(left_ident)),
     };
 
-    let mut num = 0;
-    loop {
-        let mut monkeys = monkeys.clone();
-        monkeys.entry("humn").and_modify(|humn| humn.yell = Yell::Number(num));
-        let monkeys = solve(monkeys);
+    while ident != "humn" {
+        let Yell::Operation { left, right, operation } = monkeys[ident].yell else {
+            return Err(SolveError::new("Monkey root does not depend on humn"));
+        };
+
+        let solved = if humn_side(&dependents, ident, left, right)? == Side::Left {
+            let solved = operation.solve_left(target, value(right));
+            ident = left;
+            solved
+        } else {
+            let solved = operation.solve_right(target, value(left));
+            ident = right;
+            solved
+        };
+        target = solved.ok_or_else(|| SolveError::new(format!("No number for monkey {ident} makes root's numbers equal")))?;
+    }
+
+    Ok(target)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+}
 
-        let left_val = monkeys[left_ident].yell.value().unwrap();
-        let right_val = monkeys[right_ident].yell.value().unwrap();
+/// Finds which operand of monkey `ident` depends on humn, which can only be undone if the other
+/// one does not.
+fn humn_side(dependents: &HashSet<&str>, ident: &str, left: &str, right: &str) -> Result<Side, SolveError> {
+    if !dependents.contains(left) {
+        Ok(Side::Right)
+    } else if !dependents.contains(right) {
+        Ok(Side::Left)
+    } else {
+        Err(SolveError::new(format!("Both numbers monkey {ident} waits for depend on humn")))
+    }
+}
 
-        if left_val == right_val {
-            return num;
+/// Collects humn and every monkey with a number worked out in `values` that depends on it.
+fn humn_dependents<'a>(monkeys: &HashMap<&'a str, Monkey<'a>>, values: &HashMap<&'a str, i64>) -> HashSet<&'a str> {
+    let mut waited_on_by: HashMap<&str, Vec<&str>> = HashMap::new();
+    for &ident in values.keys() {
+        if let Yell::Operation { left, right, .. } = monkeys[ident].yell {
+            waited_on_by.entry(left).or_default().push(ident);
+            waited_on_by.entry(right).or_default().push(ident);
         }
+    }
 
-        // A bit hacky, but works.
-        let delta = left_val - right_val;
-        if delta.abs() > 10000 {
-            num += delta / 10000;
-        } else {
-            num += delta.signum();
+    let mut dependents = HashSet::from(["humn"]);
+    let mut stack = vec!["humn"];
+    while let Some(ident) = stack.pop() {
+        for &waiting in waited_on_by.get(ident).into_iter().flatten() {
+            if dependents.insert(waiting) {
+                stack.push(waiting);
+            }
         }
     }
+
+    dependents
 }
 
-fn solve<'a>(mut monkeys: HashMap<&'a str, Monkey<'a>>) -> HashMap<&'a str, Monkey<'a>> {
+/// Works out the number of every monkey that root depends on.
+fn solve<'a>(monkeys: &HashMap<&'a str, Monkey<'a>>) -> Result<HashMap<&'a str, i64>, SolveError> {
+    let mut values: HashMap<&str, i64> = HashMap::new();
+    // Monkeys waiting for the numbers of others, which always form a path down from root.
+    let mut waiting: HashSet<&str> = HashSet::new();
+
     let mut stack = vec!["root"];
     while let Some(ident) = stack.pop() {
-        let monkey = &monkeys[ident];
-
-        if let Yell::Operation {
-            left,
-            right,
-            operation,
-        } = monkey.yell {
-            let left_val = monkeys[left].yell.value();
-            let right_val = monkeys[right].yell.value();
-
-            if left_val.is_none() || right_val.is_none() {
-                stack.push(ident);
-            }
-
-            if left_val.is_none() {
-                stack.push(left);
-            }
+        if values.contains_key(ident) {
+            continue;
+        }
 
-            if right_val.is_none() {
-                stack.push(right);
+        match monkeys[ident].yell {
+            Yell::Number(num) => {
+                values.insert(ident, num);
             }
+            Yell::Operation { left, right, operation } => match (values.get(left), values.get(right)) {
+                (Some(&left_val), Some(&right_val)) => {
+                    let value = operation.perform(left_val, right_val).ok_or_else(|| {
+                        SolveError::new(format!("Monkey {ident} cannot yell {left_val} {operation} {right_val}"))
+                    })?;
+                    values.insert(ident, value);
+                    waiting.remove(ident);
+                }
+                _ => {
+                    if !waiting.insert(ident) {
+                        return Err(SolveError::new(format!("Monkey {ident} waits for its own number")));
+                    }
 
-            if let Some(left_val) = left_val {
-                if let Some(right_val) = right_val {
-                    monkeys.entry(ident).and_modify(|monkey| {
-                        monkey.yell = Yell::Number(operation.perform(left_val, right_val))
-                    });
+                    stack.push(ident);
+                    stack.extend([left, right].into_iter().filter(|operand| !values.contains_key(operand)));
                 }
-            }
+            },
         }
     }
 
-    monkeys
+    Ok(values)
 }
 
 #[derive(Debug, Clone)]
-struct Monkey<'a> {
+pub struct Monkey<'a> {
     identifier: &'a str,
     yell: Yell<'a>,
 }
@@ -109,30 +169,64 @@ enum Operation {
     Div,
 }
 
-impl<'a> Yell<'a> {
-    fn value(&self) -> Option<i64> {
-        use Yell::*;
+impl Operation {
+    /// Returns `None` on overflow or division by zero.
+    fn perform(&self, left: i64, right: i64) -> Option<i64> {
+        use Operation::*;
 
         match self {
-            &Number(num) => Some(num),
-            Operation { .. } => None,
+            Add => left.checked_add(right),
+            Sub => left.checked_sub(right),
+            Mul => left.checked_mul(right),
+            Div => left.checked_div(right),
         }
     }
-}
 
-impl Operation {
-    fn perform(&self, left: i64, right: i64) -> i64 {
+    /// Finds `left` such that `left <op> right == result`, or returns `None` if there is no
+    /// such integer.
+    fn solve_left(&self, result: i64, right: i64) -> Option<i64> {
+        use Operation::*;
+
+        match self {
+            Add => result.checked_sub(right),
+            Sub => result.checked_add(right),
+            Mul if result.checked_rem(right)? != 0 => None,
+            Mul => result.checked_div(right),
+            Div if right == 0 => None,
+            Div => result.checked_mul(right),
+        }
+    }
+
+    /// Finds `right` such that `left <op> right == result`, or returns `None` if there is no
+    /// such integer.
+    fn solve_right(&self, result: i64, left: i64) -> Option<i64> {
         use Operation::*;
 
         match self {
-            Add => left + right,
-            Sub => left - right,
-            Mul => left * right,
-            Div => left / right,
+            Add => result.checked_sub(left),
+            Sub => left.checked_sub(result),
+            Mul if result.checked_rem(left)? != 0 => None,
+            Mul => result.checked_div(left),
+            Div if left.checked_rem(result)? != 0 => None,
+            Div => left.checked_div(result),
         }
     }
 }
 
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Operation::*;
+
+        let symbol = match self {
+            Add => '+',
+            Sub => '-',
+            Mul => '*',
+            Div => '/',
+        };
+        write!(f, "{symbol}")
+    }
+}
+
 impl TryFrom<char> for Operation {
     type Error = &'static str;
 
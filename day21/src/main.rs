@@ -1,13 +1,28 @@
-use std::fs;
+use std::process;
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day21::*;
 
 fn main() {
-    let contents = fs::read_to_string("day21/input.txt").expect("Failed to read puzzle input");
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).expect("Failed to read puzzle input");
+    let input = Day21::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day21::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    match Day21::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 }
 
 #[cfg(test)]
@@ -32,15 +47,111 @@ hmdt: 32";
 
     #[test]
     fn test_part_1() {
-        let sol = solve_part_1(INPUT);
+        let sol = solve_part_1(&parser::parse_input(INPUT).unwrap());
 
-        assert_eq!(sol, 152);
+        assert_eq!(sol, Ok(152));
     }
 
     #[test]
     fn test_part_2() {
-        let sol = solve_part_2(INPUT);
+        let sol = solve_part_2(&parser::parse_input(INPUT).unwrap());
+
+        assert_eq!(sol, Ok(301));
+    }
+
+    #[test]
+    fn test_part_2_humn_on_right() {
+        let input = "root: aaaa + bbbb
+aaaa: 20
+bbbb: cccc - dddd
+cccc: 30
+dddd: eeee / humn
+eeee: 40
+humn: 1";
+
+        assert_eq!(solve_part_2(&parser::parse_input(input).unwrap()), Ok(4));
+    }
+
+    #[test]
+    fn test_part_2_inexact() {
+        let monkeys = parser::parse_input("root: aaaa + bbbb\naaaa: humn * cccc\ncccc: 2\nbbbb: 7\nhumn: 1").unwrap();
+        assert!(solve_part_2(&monkeys).is_err());
+
+        let monkeys = parser::parse_input("root: aaaa + bbbb\naaaa: cccc / humn\ncccc: 7\nbbbb: 2\nhumn: 1").unwrap();
+        assert!(solve_part_2(&monkeys).is_err());
+
+        let monkeys = parser::parse_input("root: aaaa + bbbb\naaaa: humn / cccc\ncccc: 0\nbbbb: 2\nhumn: 1").unwrap();
+        assert!(solve_part_2(&monkeys).is_err());
+    }
+
+    #[test]
+    fn test_part_2_humn_on_both_sides() {
+        let monkeys = parser::parse_input("root: aaaa + bbbb\naaaa: humn * humn\nbbbb: 16\nhumn: 1").unwrap();
+        assert!(solve_part_2(&monkeys).is_err());
+
+        let monkeys = parser::parse_input("root: humn + aaaa\naaaa: humn - bbbb\nbbbb: 2\nhumn: 1").unwrap();
+        assert!(solve_part_2(&monkeys).is_err());
+    }
+
+    #[test]
+    fn test_part_2_long_chain() {
+        let name = |index: u32| -> String {
+            (0..4).rev().map(|digit| char::from(b'a' + (index / 26u32.pow(digit) % 26) as u8)).collect()
+        };
+
+        // humn + 1 + 1 + ... + 1 == 20005, with every addition done by a different monkey.
+        let mut input = format!("root: {} + zzzy\nzzzy: 20005\nzzzz: 1\nhumn: 1\n", name(0));
+        for index in 0..20_000 {
+            let next = if index == 19_999 { "humn".to_string() } else { name(index + 1) };
+            input += &format!("{}: {next} + zzzz\n", name(index));
+        }
+
+        assert_eq!(solve_part_2(&parser::parse_input(&input).unwrap()), Ok(5));
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let input = aoc_generate::generate::<Day21>(seed, 51);
+            let monkeys = parser::parse_input(&input).unwrap();
+            let humn = solve_part_2(&monkeys).unwrap();
+
+            assert_eq!(monkeys.len(), 51);
+
+            // With humn yelling the answer, both sides of root yell the same number.
+            let fixed: String = input
+                .lines()
+                .map(|line| match line.split_once(": ") {
+                    Some(("humn", _)) => format!("humn: {humn}\n"),
+                    Some(("root", job)) => format!("root: {}\n", job.replace('+', "-")),
+                    _ => format!("{line}\n"),
+                })
+                .collect();
+            assert_eq!(solve_part_1(&parser::parse_input(&fixed).unwrap()), Ok(0));
+        }
+    }
+
+    #[test]
+    fn test_generated_all_names() {
+        // Asking for more monkeys than there are four letter names gives one fewer than that.
+        let input = aoc_generate::generate::<Day21>(0, 1_000_000);
+        let monkeys = parser::parse_input(&input).unwrap();
+
+        assert_eq!(monkeys.len(), 26usize.pow(4) - 1);
+        assert_eq!(input.lines().count(), monkeys.len());
+        assert!(solve_part_2(&monkeys).is_ok());
+    }
+
+    #[test]
+    fn test_no_answer() {
+        let monkeys = parser::parse_input("root: aaaa / bbbb\naaaa: 4\nbbbb: humn - aaaa\nhumn: 4").unwrap();
+        assert!(solve_part_1(&monkeys).is_err());
+
+        let monkeys = parser::parse_input("root: aaaa + bbbb\naaaa: bbbb * humn\nbbbb: aaaa - humn\nhumn: 4").unwrap();
+        assert!(solve_part_1(&monkeys).is_err());
+        assert!(solve_part_2(&monkeys).is_err());
 
-        assert_eq!(sol, 301);
+        let monkeys = parser::parse_input("root: aaaa + bbbb\naaaa: 4\nbbbb: 3\nhumn: 4").unwrap();
+        assert!(solve_part_2(&monkeys).is_err());
     }
 }
@@ -1,45 +1,60 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+use aoc_core::ParseError;
+use aoc_parse::{lines, parse_all, unsigned};
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take},
-    character::complete::{anychar, digit1, line_ending, space1},
+    bytes::complete::tag,
+    character::complete::{anychar, space1},
     combinator::{map, map_res},
-    multi::separated_list0,
     sequence::{delimited, separated_pair, tuple},
-    Finish, IResult,
+    IResult,
 };
 
 use crate::*;
 
-pub(crate) fn parse_input(input: &str) -> HashMap<&str, Monkey> {
-    let (_, monkeys) = monkey_list(input)
-        .finish()
-        .expect("Failed to parse puzzle input");
+pub fn parse_input(input: &str) -> Result<HashMap<&str, Monkey<'_>>, ParseError> {
+    const EXPECTED: &str = "monkey like `root: pppw + sjmn` or `dbpl: 5`";
 
-    monkeys
+    let monkeys = parse_all(input, monkey_list, EXPECTED)?;
+
+    let mut known: HashSet<&str> = HashSet::with_capacity(monkeys.len());
+    for monkey in &monkeys {
+        if !known.insert(monkey.identifier) {
+            return Err(ParseError::new(input, monkey.identifier, "name not used by another monkey yet"));
+        }
+    }
+
+    if !known.contains("root") {
+        return Err(ParseError::new(input, &input[input.len()..], "monkey `root`"));
+    }
+    for monkey in &monkeys {
+        if let Yell::Operation { left, right, .. } = monkey.yell {
+            if let Some(unknown) = [left, right].into_iter().find(|name| !known.contains(name)) {
+                return Err(ParseError::new(input, unknown, "name of another monkey"));
+            }
+        }
+    }
+
+    Ok(monkeys
         .into_iter()
         .map(|monkey| (monkey.identifier, monkey))
-        .collect()
-}
-
-fn integer(input: &str) -> IResult<&str, i64> {
-    map_res(digit1, str::parse)(input)
+        .collect())
 }
 
 fn identifier(input: &str) -> IResult<&str, &str> {
-    take(4usize)(input)
+    aoc_parse::identifier(4)(input)
 }
 
 fn operation(input: &str) -> IResult<&str, Operation> {
     map_res(anychar, TryInto::try_into)(input)
 }
 
-fn number_yell(input: &str) -> IResult<&str, Yell> {
-    map(integer, Yell::Number)(input)
+fn number_yell(input: &str) -> IResult<&str, Yell<'_>> {
+    map(unsigned, Yell::Number)(input)
 }
 
-fn operation_yell(input: &str) -> IResult<&str, Yell> {
+fn operation_yell(input: &str) -> IResult<&str, Yell<'_>> {
     map(
         tuple((identifier, delimited(space1, operation, space1), identifier)),
         |(left, operation, right)| Yell::Operation {
@@ -50,19 +65,19 @@ fn operation_yell(input: &str) -> IResult<&str, Yell> {
     )(input)
 }
 
-fn yell(input: &str) -> IResult<&str, Yell> {
+fn yell(input: &str) -> IResult<&str, Yell<'_>> {
     alt((number_yell, operation_yell))(input)
 }
 
-fn monkey(input: &str) -> IResult<&str, Monkey> {
+fn monkey(input: &str) -> IResult<&str, Monkey<'_>> {
     map(
         separated_pair(identifier, tag(": "), yell),
         |(identifier, yell)| Monkey { identifier, yell },
     )(input)
 }
 
-fn monkey_list(input: &str) -> IResult<&str, Vec<Monkey>> {
-    separated_list0(line_ending, monkey)(input)
+fn monkey_list(input: &str) -> IResult<&str, Vec<Monkey<'_>>> {
+    lines(monkey)(input)
 }
 
 #[cfg(test)]
@@ -73,7 +88,7 @@ mod tests {
     fn test_integer() {
         let input = "12";
 
-        let (rem, int) = integer(input).unwrap();
+        let (rem, int) = unsigned::<i64>(input).unwrap();
 
         assert_eq!(int, 12);
         assert!(rem.is_empty());
@@ -154,4 +169,16 @@ zczc: 2";
         assert!(matches!(monkeys[1].yell, Yell::Number(2)));
         assert!(rem.is_empty());
     }
+
+    #[test]
+    fn test_parse_input_error() {
+        let err = parse_input("aaaa: 4").unwrap_err();
+        assert_eq!(err.line, 1);
+
+        let err = parse_input("root: aaaa + bbbb\naaaa: 4").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 14));
+
+        let err = parse_input("root: aaaa + aaaa\naaaa: 4\naaaa: 5").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
 }
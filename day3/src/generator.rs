@@ -0,0 +1,61 @@
+use aoc_generate::{
+    rand::{
+        seq::{IndexedRandom, SliceRandom},
+        RngExt,
+    },
+    Generated, InputRng, LOWERCASE, UPPERCASE,
+};
+
+use crate::Day3;
+
+impl Generated for Day3 {
+    /// `size` rucksacks, rounded up to whole groups of three elves.
+    ///
+    /// Like in the puzzle, the compartments of each rucksack share exactly one item type and
+    /// the rucksacks of a group share exactly one badge.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        let mut rucksacks = String::new();
+        for _ in 0..size.div_ceil(3).max(1) {
+            let mut item_types = [LOWERCASE, UPPERCASE].concat();
+            item_types.shuffle(rng);
+
+            // The other 51 item types are split between the three elves, so that only the
+            // badge is carried by all of them.
+            let (&badge, others) = item_types.split_first().unwrap();
+            for elf_types in others.chunks(others.len() / 3) {
+                rucksacks += &rucksack(rng, badge, elf_types);
+                rucksacks.push('\n');
+            }
+        }
+
+        rucksacks
+    }
+}
+
+fn rucksack(rng: &mut InputRng, badge: u8, elf_types: &[u8]) -> String {
+    let mut types = [elf_types, &[badge]].concat();
+    types.shuffle(rng);
+
+    let shared = *types.choose(rng).unwrap();
+    let (first, second) = types.split_at(rng.random_range(1..types.len()));
+    let len = rng.random_range(4..=16);
+
+    compartment(rng, first, shared, badge, len) + &compartment(rng, second, shared, badge, len)
+}
+
+/// Compartment of `len` items of the given `types`, with the `shared` item type and the
+/// `badge` if it is one of them.
+fn compartment(rng: &mut InputRng, types: &[u8], shared: u8, badge: u8, len: usize) -> String {
+    let types = [types, &[shared]].concat();
+
+    let mut items = vec![shared];
+    if badge != shared && types.contains(&badge) {
+        items.push(badge);
+    }
+    while items.len() < len {
+        items.push(*types.choose(rng).unwrap());
+    }
+    items.shuffle(rng);
+
+    String::from_utf8(items).unwrap()
+}
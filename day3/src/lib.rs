@@ -1,6 +1,28 @@
 use std::collections::BTreeSet;
 
-struct Rucksack {
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+
+mod generator;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input<'a> = Vec<Rucksack>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input).into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input)?.into())
+    }
+}
+
+pub struct Rucksack {
     first_comp: String,
     second_comp: String,
 }
@@ -30,16 +52,16 @@ impl Rucksack {
 }
 
 fn priority(ch: char) -> i32 {
-    if ('a'..='z').contains(&ch) {
+    if ch.is_ascii_lowercase() {
         (ch as i32) - ('a' as i32) + 1
-    } else if ('A'..='Z').contains(&ch) {
+    } else if ch.is_ascii_uppercase() {
         (ch as i32) - ('A' as i32) + 27
     } else {
         panic!("Invalid item in sack")
     }
 }
 
-fn badge(sacks: &[Rucksack]) -> char {
+fn badge(sacks: &[Rucksack]) -> Result<char, SolveError> {
     if let [s1, s2, s3] = sacks {
         let s1 = s1.whole();
         let s2 = s2.whole();
@@ -49,15 +71,29 @@ fn badge(sacks: &[Rucksack]) -> char {
             .collect::<BTreeSet<_>>()
             .into_iter()
             .next()
-            .unwrap()
+            .ok_or_else(|| SolveError::new("Group of elves has no badge in common"))
     } else {
-        panic!("Invalid number of sacks");
+        Err(SolveError::new("Number of elves is not a multiple of 3"))
     }
 }
 
-pub fn solve_part_1(input: &str) -> i32 {
-    let sucksacks: Vec<Rucksack> = input.lines().map(Rucksack::new).collect();
+pub fn parse_input(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    input.lines()
+        .map(|line| {
+            if let Some(i) = line.find(|ch: char| !ch.is_ascii_alphabetic()) {
+                return Err(ParseError::new(input, &line[i..], "item (a-z or A-Z)"));
+            }
+
+            if line.len() % 2 != 0 {
+                return Err(ParseError::new(input, line, "even number of items"));
+            }
 
+            Ok(Rucksack::new(line))
+        })
+        .collect()
+}
+
+pub fn solve_part_1(sucksacks: &[Rucksack]) -> i32 {
     sucksacks.iter()
         .flat_map(|sack| sack.common()
             .chars()
@@ -66,11 +102,8 @@ pub fn solve_part_1(input: &str) -> i32 {
         .sum()
 }
 
-pub fn solve_part_2(input: &str) -> i32 {
-    let sucksacks: Vec<Rucksack> = input.lines().map(Rucksack::new).collect();
-
+pub fn solve_part_2(sucksacks: &[Rucksack]) -> Result<i32, SolveError> {
     sucksacks.chunks(3)
-        .map(badge)
-        .map(priority)
+        .map(|group| badge(group).map(priority))
         .sum()
 }
@@ -1,13 +1,28 @@
-use std::{error, fs};
+use std::{error, process};
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day3::*;
 
 fn main() -> Result<(), Box<dyn error::Error>> {
-    let contents = fs::read_to_string("day3/input.txt")?;
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"))?;
+    let input = Day3::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day3::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    match Day3::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 
     Ok(())
 }
@@ -25,7 +40,7 @@ wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
 ttgJtRGJQctTZtZT
 CrZsJsPPZsGzwwsLwLmpwMDw";
 
-        let sol = solve_part_1(input);
+        let sol = solve_part_1(&parse_input(input).unwrap());
         assert_eq!(sol, 157);
     }
 
@@ -38,7 +53,24 @@ wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
 ttgJtRGJQctTZtZT
 CrZsJsPPZsGzwwsLwLmpwMDw";
 
-        let sol = solve_part_2(input);
-        assert_eq!(sol, 70);
+        let sol = solve_part_2(&parse_input(input).unwrap());
+        assert_eq!(sol, Ok(70));
+    }
+
+    #[test]
+    fn test_no_answer() {
+        assert!(solve_part_2(&parse_input("abab\ncdcd").unwrap()).is_err());
+        assert!(solve_part_2(&parse_input("aa\nbb\ncc").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let rucksacks = parse_input(&aoc_generate::generate::<Day3>(seed, 30)).unwrap();
+
+            assert_eq!(rucksacks.len(), 30);
+            assert!(solve_part_1(&rucksacks) >= 30);
+            assert!(solve_part_2(&rucksacks).unwrap() >= 10);
+        }
     }
 }
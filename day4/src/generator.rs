@@ -0,0 +1,17 @@
+use aoc_generate::{rand::RngExt, Generated, InputRng};
+
+use crate::Day4;
+
+impl Generated for Day4 {
+    /// `size` pairs of section assignments.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        let mut range = || {
+            let start = rng.random_range(1..=99);
+            format!("{start}-{}", rng.random_range(start..=99))
+        };
+
+        (0..size.max(1))
+            .map(|_| format!("{},{}\n", range(), range()))
+            .collect()
+    }
+}
@@ -1,27 +1,39 @@
-use std::{ops::RangeInclusive, str::FromStr};
-
-struct Pair(RangeInclusive<u32>, RangeInclusive<u32>);
-
-impl FromStr for Pair {
-    type Err = &'static str;
-
-    fn from_str(line: &str) -> Result<Self, Self::Err> {
-        let ranges = line
-            .split(',')
-            .map(|pair_str| {
-                let mut split = pair_str.split('-');
-                let start_str = split.next().ok_or("Invalid pair start")?.parse().unwrap();
-                let end_str = split.next().ok_or("Invalid pair end")?.parse().unwrap();
-                Ok(start_str..=end_str)
-            })
-            .collect::<Result<Vec<_>, _>>()?;
-
-        let mut r = ranges.into_iter();
-        Ok(Pair(r.next().unwrap(), r.next().unwrap()))
+use std::ops::RangeInclusive;
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+
+mod generator;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input<'a> = Vec<Pair>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input).into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input).into())
     }
 }
 
+pub struct Pair(RangeInclusive<u32>, RangeInclusive<u32>);
+
 impl Pair {
+    /// Parses a single `line` of `input`.
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (first, second) = line
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(input, line, "two ranges separated by a comma"))?;
+
+        Ok(Pair(parse_range(input, first)?, parse_range(input, second)?))
+    }
+
     fn check_contains(&self) -> bool {
         let (s1, e1) = self.0.clone().into_inner();
         let (s2, e2) = self.1.clone().into_inner();
@@ -35,10 +47,28 @@ impl Pair {
     }
 }
 
-pub fn solve_part_1(input: &str) -> i32 {
-    input.lines().map(|line| line.parse().unwrap()).filter(Pair::check_contains).count() as i32
+fn parse_range(input: &str, range: &str) -> Result<RangeInclusive<u32>, ParseError> {
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(input, range, "range like `2-4`"))?;
+
+    let section = |section: &str| {
+        section
+            .parse()
+            .map_err(|_| ParseError::new(input, section, "section number"))
+    };
+
+    Ok(section(start)?..=section(end)?)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Pair>, ParseError> {
+    input.lines().map(|line| Pair::parse(input, line)).collect()
+}
+
+pub fn solve_part_1(pairs: &[Pair]) -> i32 {
+    pairs.iter().filter(|pair| pair.check_contains()).count() as i32
 }
 
-pub fn solve_part_2(input: &str) -> i32 {
-    input.lines().map(|line| line.parse().unwrap()).filter(Pair::check_overlap).count() as i32
+pub fn solve_part_2(pairs: &[Pair]) -> i32 {
+    pairs.iter().filter(|pair| pair.check_overlap()).count() as i32
 }
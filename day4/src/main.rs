@@ -1,13 +1,28 @@
-use std::{error, fs};
+use std::{error, process};
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day4::*;
 
 fn main() -> Result<(), Box<dyn error::Error>> {
-    let contents = fs::read_to_string("day4/input.txt")?;
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"))?;
+    let input = Day4::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day4::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    match Day4::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 
     Ok(())
 }
@@ -25,7 +40,7 @@ mod tests {
 6-6,4-6
 2-6,4-8";
 
-        let sol = solve_part_1(input);
+        let sol = solve_part_1(&parse_input(input).unwrap());
         assert_eq!(sol, 2);
     }
 
@@ -38,7 +53,17 @@ mod tests {
 6-6,4-6
 2-6,4-8";
 
-        let sol = solve_part_2(input);
+        let sol = solve_part_2(&parse_input(input).unwrap());
         assert_eq!(sol, 4);
     }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let pairs = parse_input(&aoc_generate::generate::<Day4>(seed, 20)).unwrap();
+
+            assert_eq!(pairs.len(), 20);
+            assert!(solve_part_1(&pairs) <= solve_part_2(&pairs));
+        }
+    }
 }
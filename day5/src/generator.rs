@@ -0,0 +1,72 @@
+use aoc_generate::{
+    rand::{seq::IndexedRandom, RngExt},
+    Generated, InputRng, UPPERCASE,
+};
+
+use crate::Day5;
+
+impl Generated for Day5 {
+    /// Up to nine stacks of crates and `size` moves between them.
+    ///
+    /// Moves always leave a crate behind, so that no stack ends up empty.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        let stack_count = rng.random_range(3..=9);
+        let mut stacks: Vec<Vec<u8>> = (0..stack_count)
+            .map(|i| {
+                let height = rng.random_range(if i == 0 { 2 } else { 1 }..=8);
+                (0..height)
+                    .map(|_| *UPPERCASE.choose(rng).unwrap())
+                    .collect()
+            })
+            .collect();
+
+        let mut input = drawing(&stacks);
+        input.push('\n');
+
+        for _ in 0..size.max(1) {
+            let movable: Vec<usize> = (0..stack_count).filter(|&i| stacks[i].len() > 1).collect();
+            let from = *movable.choose(rng).unwrap();
+            let to = loop {
+                let to = rng.random_range(0..stack_count);
+                if to != from {
+                    break to;
+                }
+            };
+            let count = rng.random_range(1..stacks[from].len());
+
+            // Part 1 moves crates one by one and part 2 all at once, but either way the stacks
+            // end up with the same heights, which is all that keeps later moves valid.
+            let remaining = stacks[from].len() - count;
+            let crates = stacks[from].split_off(remaining);
+            stacks[to].extend(crates);
+
+            input += &format!("move {count} from {} to {}\n", from + 1, to + 1);
+        }
+
+        input
+    }
+}
+
+/// Drawing of the stacks like at the top of the puzzle input, followed by the stack numbers.
+fn drawing(stacks: &[Vec<u8>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut drawing = String::new();
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(&crt) => format!("[{}]", crt as char),
+                None => "   ".to_string(),
+            })
+            .collect();
+        drawing += row.join(" ").trim_end();
+        drawing.push('\n');
+    }
+
+    let numbers: Vec<String> = (1..=stacks.len()).map(|i| format!(" {i} ")).collect();
+    drawing += numbers.join(" ").trim_end();
+    drawing.push('\n');
+
+    drawing
+}
@@ -1,5 +1,26 @@
+mod generator;
 pub mod parser;
 
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input<'a> = (Vec<CrateStack>, Vec<Move>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parser::parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(&input.0, &input.1)?.into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(&input.0, &input.1)?.into())
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct Crate(char);
 
@@ -12,34 +33,66 @@ pub struct Move {
     to: usize,
 }
 
-pub fn solve_part_1(mut stacks: Vec<CrateStack>, moves: Vec<Move>) -> String {
+pub fn solve_part_1(stacks: &[CrateStack], moves: &[Move]) -> Result<String, SolveError> {
+    let mut stacks = stacks.to_vec();
     for mov in moves {
-        let from = mov.from - 1;
-        let to = mov.to - 1;
+        let (from, to) = mov.stack_indices(&stacks)?;
         for _ in 0..mov.count {
-            let crt = stacks[from].pop().expect("Encountered empty stack");
+            let crt = stacks[from].pop().expect("Move was checked to have enough crates");
             stacks[to].push(crt);
         }
     }
 
-    stacks.iter()
-        .fold(String::new(), |acc, stack| {
-            format!("{}{}", acc, stack.last().expect("Encountered empty stack").0)
-        })
+    top_crates(&stacks)
 }
 
-pub fn solve_part_2(mut stacks: Vec<CrateStack>, moves: Vec<Move>) -> String {
+pub fn solve_part_2(stacks: &[CrateStack], moves: &[Move]) -> Result<String, SolveError> {
+    let mut stacks = stacks.to_vec();
     for mov in moves {
-        let from = mov.from - 1;
-        let to = mov.to - 1;
+        let (from, to) = mov.stack_indices(&stacks)?;
 
         let src_stack = &mut stacks[from];
         let crates = src_stack.split_off(src_stack.len() - mov.count);
         stacks[to].extend(crates);
     }
 
-    stacks.iter()
-        .fold(String::new(), |acc, stack| {
-            format!("{}{}", acc, stack.last().expect("Encountered empty stack").0)
+    top_crates(&stacks)
+}
+
+impl Move {
+    /// Indices of the stacks to move crates between, if both exist and there are enough
+    /// crates to move.
+    fn stack_indices(&self, stacks: &[CrateStack]) -> Result<(usize, usize), SolveError> {
+        let index = |number: usize| {
+            number
+                .checked_sub(1)
+                .filter(|&i| i < stacks.len())
+                .ok_or_else(|| SolveError::new(format!("There is no stack {number}")))
+        };
+        let (from, to) = (index(self.from)?, index(self.to)?);
+
+        if stacks[from].len() < self.count {
+            return Err(SolveError::new(format!(
+                "Cannot move {} crates from stack {} with {} crates",
+                self.count,
+                self.from,
+                stacks[from].len()
+            )));
+        }
+
+        Ok((from, to))
+    }
+}
+
+fn top_crates(stacks: &[CrateStack]) -> Result<String, SolveError> {
+    stacks
+        .iter()
+        .enumerate()
+        .map(|(i, stack)| {
+            stack
+                .last()
+                .map(|crt| crt.0)
+                .ok_or_else(|| SolveError::new(format!("Stack {} ends up empty", i + 1)))
         })
+        .collect()
 }
@@ -1,14 +1,28 @@
-use std::fs;
+use std::process;
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day5::*;
 
 fn main() {
-    let contents = fs::read_to_string("day5/input.txt").unwrap();
-    let (stacks, moves) = parser::parse_input(&contents);
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).unwrap();
+    let input = Day5::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    println!("Part 1: {}", solve_part_1(stacks.clone(), moves.clone()));
+    match Day5::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(stacks, moves));
+    match Day5::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 }
 
 #[cfg(test)]
@@ -27,17 +41,39 @@ move 1 from 1 to 2";
 
     #[test]
     fn test_part_1() {
-        let (stacks, moves) = parser::parse_input(INPUT);
-        let sol = solve_part_1(stacks, moves);
+        let (stacks, moves) = parser::parse_input(INPUT).unwrap();
+        let sol = solve_part_1(&stacks, &moves);
 
-        assert_eq!(sol, "CMZ");
+        assert_eq!(sol.unwrap(), "CMZ");
     }
 
     #[test]
     fn test_part_2() {
-        let (stacks, moves) = parser::parse_input(INPUT);
-        let sol = solve_part_2(stacks, moves);
+        let (stacks, moves) = parser::parse_input(INPUT).unwrap();
+        let sol = solve_part_2(&stacks, &moves);
+
+        assert_eq!(sol.unwrap(), "MCD")
+    }
+
+    #[test]
+    fn test_no_answer() {
+        for moves in ["move 1 from 0 to 1", "move 1 from 1 to 4", "move 4 from 1 to 2", "move 1 from 3 to 2"] {
+            let (stacks, moves) = parser::parse_input(&INPUT.replace("move 1 from 2 to 1", moves)).unwrap();
+
+            assert!(solve_part_1(&stacks, &moves).is_err(), "{moves:?}");
+            assert!(solve_part_2(&stacks, &moves).is_err(), "{moves:?}");
+        }
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let input = aoc_generate::generate::<Day5>(seed, 20);
+            let (stacks, moves) = parser::parse_input(&input).unwrap();
 
-        assert_eq!(sol, "MCD")
+            assert_eq!(moves.len(), 20);
+            assert_eq!(solve_part_1(&stacks, &moves).unwrap().len(), stacks.len());
+            assert_eq!(solve_part_2(&stacks, &moves).unwrap().len(), stacks.len());
+        }
     }
 }
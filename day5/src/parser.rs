@@ -1,18 +1,26 @@
+use aoc_core::{expect_end, ParseError};
+use aoc_parse::unsigned;
 use nom::{
     branch::alt,
     character::complete::{char, alpha1, line_ending, digit1},
     combinator::map,
     sequence::{delimited, tuple, preceded, terminated},
-    IResult, bytes::complete::tag, multi::{separated_list1, many1},
+    IResult, Finish, bytes::complete::tag, multi::{separated_list1, many1},
 };
 
 use crate::*;
 
-pub fn parse_input(input: &str) -> (Vec<CrateStack>, Vec<Move>) {
-    tuple((
+pub fn parse_input(input: &str) -> Result<(Vec<CrateStack>, Vec<Move>), ParseError> {
+    let (rest, (stacks, moves)) = tuple((
         terminated(crate_stacks, stack_numbers),
         moves_list
-    ))(input).unwrap().1
+    ))(input)
+        .finish()
+        .map_err(|err| ParseError::new(input, err.input, "crate stacks, stack numbers and moves"))?;
+
+    expect_end(input, rest, "move like `move 1 from 2 to 3`")?;
+
+    Ok((stacks, moves))
 }
 
 fn crate_box(input: &str) -> IResult<&str, Option<Crate>> {
@@ -38,7 +46,8 @@ fn crate_line(input: &str) -> IResult<&str, Vec<Option<Crate>>> {
 fn crate_stacks(input: &str) -> IResult<&str, Vec<CrateStack>> {
     let (input, lines) = separated_list1(line_ending, crate_line)(input)?;
 
-    let mut stacks: Vec<CrateStack> = vec![vec![]; lines.last().unwrap().len()];
+    let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+    let mut stacks: Vec<CrateStack> = vec![vec![]; width];
     for line in lines.into_iter().rev() {
         for (i, crt) in line.into_iter().enumerate() {
             if let Some(crt) = crt {
@@ -67,15 +76,11 @@ fn stack_numbers(input: &str) -> IResult<&str, ()> {
 fn single_move(input: &str) -> IResult<&str, Move> {
     map(
         tuple((
-            preceded(tag("move "), digit1),
-            preceded(tag(" from "), digit1),
-            preceded(tag(" to "), digit1),
+            preceded(tag("move "), unsigned),
+            preceded(tag(" from "), unsigned),
+            preceded(tag(" to "), unsigned),
         )),
-        |(count, from, to): (&str, &str, &str)| Move {
-            count: count.parse().unwrap(),
-            from: from.parse().unwrap(),
-            to: to.parse().unwrap(),
-        }
+        |(count, from, to)| Move { count, from, to }
     )(input)
 }
 
@@ -129,6 +134,13 @@ mod tests {
         assert_eq!(rem, "\n");
     }
 
+    #[test]
+    fn test_crate_stacks_ragged() {
+        let (_, stacks) = crate_stacks("    [A] [B]\n[C]").unwrap();
+
+        assert_eq!(stacks, vec![vec![Crate('C')], vec![Crate('A')], vec![Crate('B')]]);
+    }
+
     #[test]
     fn test_stack_numbers() {
         let (rem, _) = stack_numbers("\n 1   2   3   4\n\nmove").unwrap();
@@ -170,8 +182,21 @@ move 2 from 2 to 2").unwrap();
 
 move 1 from 2 to 3";
 
-        let (stacks, moves) = parse_input(input);
+        let (stacks, moves) = parse_input(input).unwrap();
         assert_eq!(stacks, vec![vec![Crate('A')], vec![Crate('B')], vec![Crate('C')]]);
         assert_eq!(moves, vec![Move { count: 1, from: 2, to: 3 }]);
     }
+
+    #[test]
+    fn test_parse_input_error() {
+        let input = r"[A] [B] [C]
+ 1   2   3
+
+move 1 from 2 to 3
+move x from 1 to 2";
+
+        let err = parse_input(input).unwrap_err();
+        assert_eq!((err.line, err.column), (5, 1));
+        assert_eq!(err.snippet, "move x from 1 to 2");
+    }
 }
@@ -0,0 +1,33 @@
+use aoc_generate::{
+    rand::{
+        seq::{IndexedRandom, SliceRandom},
+        RngExt,
+    },
+    Generated, InputRng, LOWERCASE,
+};
+
+use crate::Day6;
+
+impl Generated for Day6 {
+    /// Datastream of `size` characters, with a start-of-message marker at a random place.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        let len = size.max(14);
+        let marker_at = rng.random_range(0..=len - 14);
+
+        // Repeating only three letters before the marker keeps any marker from showing up early.
+        let mut noise = LOWERCASE.to_vec();
+        noise.shuffle(rng);
+        let noise = &noise[..3];
+
+        let mut marker = LOWERCASE.to_vec();
+        marker.shuffle(rng);
+
+        let mut signal: Vec<u8> = (0..marker_at)
+            .map(|_| *noise.choose(rng).unwrap())
+            .collect();
+        signal.extend(&marker[..14]);
+        signal.extend((signal.len()..len).map(|_| *LOWERCASE.choose(rng).unwrap()));
+
+        String::from_utf8(signal).unwrap() + "\n"
+    }
+}
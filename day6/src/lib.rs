@@ -1,34 +1,82 @@
+use aoc_core::{Answer, ParseError, Solution, SolveError};
 
-pub fn solve_part_1(input: &str) -> usize {
-    let mut buff = Vec::with_capacity(4);
+mod generator;
 
-    for (i, char) in input.chars().enumerate() {
-        if let Some(j) = buff.iter().position(|&x| x == char) {
-            buff.drain(0..=j);
-        }
+pub struct Day6;
 
-        buff.push(char);
-        if buff.len() >= 4 {
-            return i + 1;
+/// Puzzle parameters, which default to the ones of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// Number of different characters in a row that make a start-of-packet marker.
+    pub packet_marker_len: usize,
+    /// Number of different characters in a row that make a start-of-message marker.
+    pub message_marker_len: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            packet_marker_len: 4,
+            message_marker_len: 14,
         }
     }
+}
+
+impl Solution for Day6 {
+    type Input<'a> = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input, &Params::default())?.into())
+    }
 
-    unreachable!()
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input, &Params::default())?.into())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<char>, ParseError> {
+    let signal = input.trim_end();
+
+    if let Some(i) = signal.find(|ch: char| !ch.is_ascii_lowercase()) {
+        return Err(ParseError::new(input, &signal[i..], "lowercase letter"));
+    }
+
+    Ok(signal.chars().collect())
 }
 
-pub fn solve_part_2(input: &str) -> usize {
-    let mut buff = Vec::with_capacity(4);
+pub fn solve_part_1(signal: &[char], params: &Params) -> Result<usize, SolveError> {
+    find_marker(signal, params.packet_marker_len)?
+        .ok_or_else(|| SolveError::new("No start-of-packet marker in the signal"))
+}
+
+pub fn solve_part_2(signal: &[char], params: &Params) -> Result<usize, SolveError> {
+    find_marker(signal, params.message_marker_len)?
+        .ok_or_else(|| SolveError::new("No start-of-message marker in the signal"))
+}
+
+/// Number of characters read until the last `len` of them are all different.
+fn find_marker(signal: &[char], len: usize) -> Result<Option<usize>, SolveError> {
+    // Markers are made of different lowercase letters, of which there are only 26.
+    if !(1..=26).contains(&len) {
+        return Err(SolveError::new(format!("Marker cannot be {len} characters long, only 1 to 26")));
+    }
+
+    let mut buff = Vec::with_capacity(len);
 
-    for (i, char) in input.chars().enumerate() {
+    for (i, &char) in signal.iter().enumerate() {
         if let Some(j) = buff.iter().position(|&x| x == char) {
             buff.drain(0..=j);
         }
 
         buff.push(char);
-        if buff.len() >= 14 {
-            return i + 1;
+        if buff.len() >= len {
+            return Ok(Some(i + 1));
         }
     }
 
-    unreachable!()
+    Ok(None)
 }
@@ -1,17 +1,34 @@
-use std::fs;
+use std::process;
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day6::*;
 
 fn main() {
-    let contents = fs::read_to_string("day6/input.txt").unwrap();
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).unwrap();
+    let input = Day6::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day6::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    match Day6::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::SolveError;
+
     use super::*;
 
     const INPUTS: [&str; 5] = [
@@ -27,9 +44,9 @@ mod tests {
         let answers = [7, 5, 6, 10, 11];
 
         for (input, answer) in INPUTS.iter().zip(answers) {
-            let sol = solve_part_1(input);
+            let sol = solve_part_1(&parse_input(input).unwrap(), &Params::default());
 
-            assert_eq!(sol, answer)
+            assert_eq!(sol, Ok(answer))
         }
     }
 
@@ -38,8 +55,38 @@ mod tests {
         let answers = [19, 23, 23, 29, 26];
 
         for (input, answer) in INPUTS.iter().zip(answers) {
-            let sol = solve_part_2(input);
+            let sol = solve_part_2(&parse_input(input).unwrap(), &Params::default());
 
-            assert_eq!(sol, answer)
+            assert_eq!(sol, Ok(answer))
         }}
+
+    #[test]
+    fn test_no_marker() {
+        let signal = parse_input("abcabcabcabcabc").unwrap();
+
+        assert_eq!(solve_part_1(&signal, &Params::default()), Err(SolveError::new("No start-of-packet marker in the signal")));
+        assert!(solve_part_2(&parse_input("abcdabcdabcdabcd").unwrap(), &Params::default()).is_err());
+    }
+
+    #[test]
+    fn test_marker_len() {
+        let signal = parse_input(INPUTS[0]).unwrap();
+        let params = Params { packet_marker_len: 2, message_marker_len: 5 };
+
+        assert_eq!(solve_part_1(&signal, &params), Ok(2));
+        assert_eq!(solve_part_2(&signal, &params), Ok(8));
+
+        assert!(solve_part_1(&signal, &Params { packet_marker_len: 0, ..params.clone() }).is_err());
+        assert!(solve_part_2(&signal, &Params { message_marker_len: 27, ..params }).is_err());
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let signal = parse_input(&aoc_generate::generate::<Day6>(seed, 100)).unwrap();
+
+            assert_eq!(signal.len(), 100);
+            assert!(solve_part_1(&signal, &Params::default()).unwrap() < solve_part_2(&signal, &Params::default()).unwrap());
+        }
+    }
 }
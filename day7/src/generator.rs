@@ -0,0 +1,98 @@
+use aoc_generate::{rand::RngExt, word, Generated, InputRng, LOWERCASE};
+
+use crate::Day7;
+
+impl Generated for Day7 {
+    /// Terminal output of browsing a filesystem of `size` files and directories.
+    ///
+    /// File sizes add up to between 45 and 65 million, so that there is always less space
+    /// left than the update needs, but more than it would with a full disk.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        let mut dirs = vec![Directory::default()];
+        let mut weights = Vec::new();
+
+        for _ in 0..size.max(1) {
+            let parent = rng.random_range(0..dirs.len());
+            let name = loop {
+                let len = rng.random_range(1..=8);
+                let mut name = word(rng, LOWERCASE, len);
+                if rng.random_bool(0.5) {
+                    name = format!("{name}.{}", word(rng, LOWERCASE, 3));
+                }
+                if !dirs[parent]
+                    .entries
+                    .iter()
+                    .any(|entry| entry.name() == name)
+                {
+                    break name;
+                }
+            };
+
+            if rng.random_bool(0.3) {
+                dirs.push(Directory::default());
+                let index = dirs.len() - 1;
+                dirs[parent].entries.push(Entry::Directory(name, index));
+            } else {
+                weights.push(rng.random_range(1..=1000u64));
+                dirs[parent]
+                    .entries
+                    .push(Entry::File(name, weights.len() - 1));
+            }
+        }
+
+        if weights.is_empty() {
+            weights.push(1);
+            dirs[0].entries.push(Entry::File("a".to_string(), 0));
+        }
+
+        let total = rng.random_range(45_000_000..=65_000_000u64);
+        let weight_sum: u64 = weights.iter().sum();
+        let sizes: Vec<u64> = weights
+            .iter()
+            .map(|weight| (weight * total / weight_sum).max(1))
+            .collect();
+
+        let mut output = String::from("$ cd /\n");
+        browse(&dirs, 0, &sizes, &mut output);
+        output
+    }
+}
+
+#[derive(Default)]
+struct Directory {
+    entries: Vec<Entry>,
+}
+
+enum Entry {
+    /// File name and index of its size.
+    File(String, usize),
+    /// Directory name and index of its contents.
+    Directory(String, usize),
+}
+
+impl Entry {
+    fn name(&self) -> &str {
+        match self {
+            Entry::File(name, _) | Entry::Directory(name, _) => name,
+        }
+    }
+}
+
+/// Lists directory `index`, then changes into each of its subdirectories and back.
+fn browse(dirs: &[Directory], index: usize, sizes: &[u64], output: &mut String) {
+    output.push_str("$ ls\n");
+    for entry in &dirs[index].entries {
+        match entry {
+            Entry::File(name, file) => output.push_str(&format!("{} {name}\n", sizes[*file])),
+            Entry::Directory(name, _) => output.push_str(&format!("dir {name}\n")),
+        }
+    }
+
+    for entry in &dirs[index].entries {
+        if let Entry::Directory(name, dir) = entry {
+            output.push_str(&format!("$ cd {name}\n"));
+            browse(dirs, *dir, sizes, output);
+            output.push_str("$ cd ..\n");
+        }
+    }
+}
@@ -1,5 +1,46 @@
+mod generator;
 pub mod parser;
 
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+
+pub struct Day7;
+
+/// Puzzle parameters, which default to the ones of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// Largest size of the directories summed up in part 1.
+    pub small_dir_size: usize,
+    pub disk_size: usize,
+    /// Free space the update needs.
+    pub needed_space: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            small_dir_size: 100000,
+            disk_size: 70000000,
+            needed_space: 30000000,
+        }
+    }
+}
+
+impl Solution for Day7 {
+    type Input<'a> = FsEntry;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parser::parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input, &Params::default()).into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input, &Params::default())?.into())
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 enum FsEntryType {
     File { size: usize },
@@ -36,16 +77,14 @@ impl FsEntry {
     }
 }
 
-pub fn solve_part_1(input: FsEntry) -> usize {
-    let root = input;
-
+pub fn solve_part_1(root: &FsEntry, params: &Params) -> usize {
     let mut dirs: Vec<&FsEntry> = root.dirs().collect();
     let mut total: usize = 0;
     while let Some(dir) = dirs.pop() {
         dirs.extend(dir.dirs());
 
         let size = calc_size(dir);
-        if size <= 100000 {
+        if size <= params.small_dir_size {
             total += size;
         }
     }
@@ -56,30 +95,30 @@ pub fn solve_part_1(input: FsEntry) -> usize {
 fn calc_size(entry: &FsEntry) -> usize {
     match entry.entry_type {
         FsEntryType::File { size } => size,
-        FsEntryType::Directory => entry.children.iter().fold(0usize, |acc, ch| acc + calc_size(ch))
+        FsEntryType::Directory => entry.children.iter().fold(0usize, |acc, ch| acc.saturating_add(calc_size(ch)))
     }
 }
 
-pub fn solve_part_2(input: FsEntry) -> usize {
-    let root = input;
+pub fn solve_part_2(root: &FsEntry, params: &Params) -> Result<usize, SolveError> {
+    let taken_size = calc_size(root);
+    let free_size = params
+        .disk_size
+        .checked_sub(taken_size)
+        .ok_or_else(|| SolveError::new(format!("Files take up {taken_size}, more than the whole disk")))?;
+    let missing_size = params.needed_space.saturating_sub(free_size);
 
-    let max_size = 70000000usize;
-    let needed_size = 30000000usize;
-    let taken_size = calc_size(&root);
-    let free_size = max_size - taken_size;
-    let missing_size = needed_size - free_size;
+    let mut to_delete: Option<usize> = None;
 
-    let mut to_delete = max_size;
-
-    let mut dirs: Vec<&FsEntry> = root.dirs().collect();
+    let mut dirs: Vec<&FsEntry> = vec![root];
     while let Some(dir) = dirs.pop() {
         dirs.extend(dir.dirs());
 
         let size = calc_size(dir);
-        if size >= missing_size && size < to_delete {
-            to_delete = size;
+        if size >= missing_size && to_delete.is_none_or(|to_delete| size < to_delete) {
+            to_delete = Some(size);
         }
     }
 
-    to_delete
+    // The root directory is always a candidate, so something was found.
+    Ok(to_delete.unwrap_or(taken_size))
 }
@@ -1,15 +1,29 @@
-use std::fs;
+use std::process;
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day7::*;
 
 fn main() {
-    let contents = fs::read_to_string("day7/input.txt").unwrap();
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).unwrap();
 
-    let input = parser::parse_input(&contents);
+    let input = Day7::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    println!("Part 1: {}", solve_part_1(input.clone()));
+    match Day7::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(input));
+    match Day7::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 }
 
 #[cfg(test)]
@@ -42,19 +56,49 @@ $ ls
 
     #[test]
     fn test_part_1() {
-        let input = parser::parse_input(INPUT);
+        let input = parser::parse_input(INPUT).unwrap();
 
-        let sol = solve_part_1(input);
+        let sol = solve_part_1(&input, &Params::default());
 
         assert_eq!(sol, 95437);
     }
 
     #[test]
     fn test_part_2() {
-        let input = parser::parse_input(INPUT);
+        let input = parser::parse_input(INPUT).unwrap();
+
+        let sol = solve_part_2(&input, &Params::default());
+
+        assert_eq!(sol, Ok(24933642));
+    }
+
+    #[test]
+    fn test_bigger_disk() {
+        let input = parser::parse_input(INPUT).unwrap();
+        let params = Params { disk_size: 100000000, ..Params::default() };
+
+        assert_eq!(solve_part_2(&input, &params), Ok(584));
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let input = parser::parse_input(&aoc_generate::generate::<Day7>(seed, 50)).unwrap();
+
+            solve_part_1(&input, &Params::default());
+            assert!(solve_part_2(&input, &Params::default()).unwrap() <= 70000000);
+        }
+    }
 
-        let sol = solve_part_2(input);
+    #[test]
+    fn test_delete_root() {
+        let input = parser::parse_input("$ cd /\n$ ls\ndir a\n50000000 b.txt").unwrap();
+        assert_eq!(solve_part_2(&input, &Params::default()), Ok(50000000));
+    }
 
-        assert_eq!(sol, 24933642);
+    #[test]
+    fn test_no_answer() {
+        let input = parser::parse_input("$ cd /\n$ ls\ndir a\n80000000 b.txt").unwrap();
+        assert!(solve_part_2(&input, &Params::default()).is_err());
     }
 }
@@ -1,8 +1,9 @@
+use aoc_core::{expect_end, ParseError};
 use nom::{
     bytes::complete::tag,
     character::complete::{digit1, space1, line_ending, not_line_ending},
     sequence::{preceded, separated_pair, delimited, terminated},
-    IResult, multi::separated_list0, combinator::{opt, map}, branch::alt,
+    IResult, Finish, multi::separated_list0, combinator::{opt, map, map_res}, branch::alt,
 };
 
 use crate::*;
@@ -13,46 +14,56 @@ enum Command {
     List,
 }
 
-pub fn parse_input(mut input: &str) -> FsEntry {
+pub fn parse_input(input: &str) -> Result<FsEntry, ParseError> {
     let mut root  = FsEntry::new("", FsEntryType::Directory);
     let mut current_path: Vec<String> = vec![];
 
-    while let Ok((rem, cmd)) = command(input) {
-        input = rem;
+    let mut rest = input;
+    while let Ok((rem, cmd)) = command(rest) {
+        let cmd_input = rest;
+        rest = rem;
         match cmd {
             Command::ChangeDirectory(dir_name) => {
                 match dir_name.as_str() {
                     "/" => current_path = vec![],
-                    ".." => { current_path.pop().expect("Cannot leave root directory"); },
+                    ".." => {
+                        current_path
+                            .pop()
+                            .ok_or_else(|| ParseError::new(input, cmd_input, "directory other than root to leave"))?;
+                    },
                     _ => current_path.push(dir_name),
                 }
             },
             Command::List => {
-                let (rem, entries) = entries(input).expect("Failed to read ls output");
-                input = rem;
+                let (rem, entries) = entries(rest)
+                    .finish()
+                    .map_err(|err| ParseError::new(input, err.input, "directory entry like `dir a` or `123 b.txt`"))?;
+                rest = rem;
+
+                let mut current_dir = Some(&mut root);
+                for name in &current_path {
+                    current_dir = current_dir.and_then(|dir| dir.get_dir(name));
+                }
 
-                let mut current_dir = current_path
-                    .iter()
-                    .fold(&mut root, |dir, name| dir.get_dir(name).unwrap_or_else(|| panic!("Directory {name} does not exist")));
+                let current_dir = current_dir
+                    .ok_or_else(|| ParseError::new(input, cmd_input, "listing of a directory seen in a previous `ls`"))?;
                 current_dir.children = entries;
             },
         }
     }
 
-    assert_eq!(input, "");
+    expect_end(input, rest, "command like `$ cd dir` or `$ ls`")?;
 
-    root
+    Ok(root)
 }
 
 fn file(input: &str) -> IResult<&str, (String, usize)> {
-    let (input, (size_str, file_name)) = separated_pair(
-        digit1,
+    let (input, (size, file_name)) = separated_pair(
+        map_res(digit1, str::parse),
         space1,
         not_line_ending
     )(input)?;
 
-    let size: usize = size_str.parse().unwrap();
-
     Ok((input, (file_name.to_string(), size)))
 }
 
@@ -191,4 +202,13 @@ dir b
         assert_eq!(cmd, Command::List);
         assert_eq!(rem, "");
     }
+
+    #[test]
+    fn test_parse_input_error() {
+        let err = parse_input("$ cd /\n$ cd ..").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = parse_input("$ cd /\n$ ls\n12x a.txt").unwrap_err();
+        assert_eq!(err.line, 3);
+    }
 }
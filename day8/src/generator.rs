@@ -0,0 +1,19 @@
+use aoc_generate::{rand::RngExt, Generated, InputRng};
+
+use crate::Day8;
+
+impl Generated for Day8 {
+    /// Square forest `size` trees wide.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        let side = size.max(1);
+
+        (0..side)
+            .map(|_| {
+                let row: String = (0..side)
+                    .map(|_| char::from(b'0' + rng.random_range(0..=9)))
+                    .collect();
+                row + "\n"
+            })
+            .collect()
+    }
+}
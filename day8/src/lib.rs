@@ -1,109 +1,103 @@
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_grid::{Grid, Position};
+use aoc_visual::{Image, Pictured};
+
+mod generator;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input<'a> = Forest;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input).into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input).into())
+    }
+}
+
+impl Pictured for Day8 {
+    /// Tree heights, from dark for 0 to bright green for 9.
+    fn image(input: &Self::Input<'_>) -> Image {
+        Image::from_grid(&input.trees, 8, |&Tree(height)| {
+            [10 + height * 8, 40 + height * 22, 10 + height * 6]
+        })
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 struct Tree(u8);
 
 #[derive(Debug)]
-#[allow(dead_code)]
-struct Forest {
-    width: usize,
-    height: usize,
-    trees: Vec<Tree>,
+pub struct Forest {
+    trees: Grid<Tree>,
 }
 
 impl Forest {
-    fn position(&self, idx: usize) -> (usize, usize) {
-        (idx % self.width, idx / self.width)
+    fn look_up(&self, col: i64, row: i64) -> impl Iterator<Item = &Tree> {
+        self.trees.ray((col, row), (0, -1))
     }
 
-    fn look_up(&self, col: usize, row: usize) -> impl Iterator<Item = &Tree> {
-        self.trees
-            .iter()
-            .skip(col)
-            .step_by(self.width)
-            .take(row)
-            .rev()
+    fn look_down(&self, col: i64, row: i64) -> impl Iterator<Item = &Tree> {
+        self.trees.ray((col, row), (0, 1))
     }
 
-    fn look_down(&self, col: usize, row: usize) -> impl Iterator<Item = &Tree> {
-        self.trees
-            .iter()
-            .skip(col)
-            .step_by(self.width)
-            .skip(row + 1)
+    fn look_left(&self, col: i64, row: i64) -> impl Iterator<Item = &Tree> {
+        self.trees.ray((col, row), (-1, 0))
     }
 
-    fn look_left(&self, col: usize, row: usize) -> impl Iterator<Item = &Tree> {
-        self.trees
-            .iter()
-            .skip(row * self.width)
-            .take(col)
-            .rev()
+    fn look_right(&self, col: i64, row: i64) -> impl Iterator<Item = &Tree> {
+        self.trees.ray((col, row), (1, 0))
     }
 
-    fn look_right(&self, col: usize, row: usize) -> impl Iterator<Item = &Tree> {
-        self.trees
-            .iter()
-            .skip(col + row * self.width + 1)
-            .take(self.width - col - 1)
+    fn views(&self, (col, row): Position) -> [Box<dyn Iterator<Item = &Tree> + '_>; 4] {
+        [
+            Box::new(self.look_up(col, row)),
+            Box::new(self.look_down(col, row)),
+            Box::new(self.look_left(col, row)),
+            Box::new(self.look_right(col, row)),
+        ]
     }
 }
 
-fn check_visible(forest: &Forest, i: usize) -> bool {
-    let (col, row) = forest.position(i);
-    let tree = &forest.trees[i];
-
-    let ranges: [Box<dyn Iterator<Item=&Tree>>; 4] = [
-        Box::new(forest.look_up(col, row)),
-        Box::new(forest.look_down(col, row)),
-        Box::new(forest.look_left(col, row)),
-        Box::new(forest.look_right(col, row)),
-    ];
+fn check_visible(forest: &Forest, pos: Position) -> bool {
+    let tree = &forest.trees[pos];
 
-    ranges
+    forest
+        .views(pos)
         .into_iter()
         .any(|mut r| r.all(|t| t < tree))
 }
 
-pub fn solve_part_1(input: &str) -> usize {
-    let width = input.lines().next().unwrap().len();
-    let height = input.lines().count();
-    let trees: Vec<Tree> = input
-        .lines()
-        .flat_map(|line| {
-            line.chars()
-                .map(|ch| ch.to_digit(10).expect("Invalid digit encountered"))
-        })
-        .map(|d| Tree(d as u8))
-        .collect();
+pub fn parse_input(input: &str) -> Result<Forest, ParseError> {
+    let trees = Grid::parse(input, "tree height digit", |ch| {
+        ch.to_digit(10).map(|d| Tree(d as u8))
+    })?;
 
-    assert_eq!(width * height, trees.len());
-    let forest = Forest {
-        width,
-        height,
-        trees,
-    };
+    Ok(Forest { trees })
+}
 
+pub fn solve_part_1(forest: &Forest) -> usize {
     let count_visible = forest
         .trees
-        .iter()
-        .enumerate()
-        .filter(|(i, _)| check_visible(&forest, *i))
+        .positions()
+        .filter(|&pos| check_visible(forest, pos))
         .count();
 
     count_visible
 }
 
-fn scenic_score(forest: &Forest, i: usize) -> usize {
-    let (col, row) = forest.position(i);
-    let tree = &forest.trees[i];
-
-    let ranges: [Box<dyn Iterator<Item=&Tree>>; 4] = [
-        Box::new(forest.look_up(col, row)),
-        Box::new(forest.look_down(col, row)),
-        Box::new(forest.look_left(col, row)),
-        Box::new(forest.look_right(col, row)),
-    ];
+fn scenic_score(forest: &Forest, pos: Position) -> usize {
+    let tree = &forest.trees[pos];
 
-    let view_distances = ranges
+    let view_distances = forest
+        .views(pos)
         .into_iter()
         .map(|r| {
             let mut eq = true;
@@ -124,29 +118,10 @@ fn scenic_score(forest: &Forest, i: usize) -> usize {
     view_distances.product()
 }
 
-pub fn solve_part_2(input: &str) -> usize {
-    let width = input.lines().next().unwrap().len();
-    let height = input.lines().count();
-    let trees: Vec<Tree> = input
-        .lines()
-        .flat_map(|line| {
-            line.chars()
-                .map(|ch| ch.to_digit(10).expect("Invalid digit encountered"))
-        })
-        .map(|d| Tree(d as u8))
-        .collect();
-
-    assert_eq!(width * height, trees.len());
-    let forest = Forest {
-        width,
-        height,
-        trees,
-    };
-
+pub fn solve_part_2(forest: &Forest) -> usize {
     let scenic_scores = forest.trees
-        .iter()
-        .enumerate()
-        .map(|(i, _)| scenic_score(&forest, i));
+        .positions()
+        .map(|pos| scenic_score(forest, pos));
 
     scenic_scores.max().expect("No scenic score")
 }
@@ -158,9 +133,7 @@ mod tests {
     #[test]
     fn test_look_up() {
         let forest = Forest {
-            width: 3,
-            height: 3,
-            trees: vec![
+            trees: Grid::from_vec(3, vec![
                 Tree(1),
                 Tree(2),
                 Tree(3),
@@ -170,21 +143,19 @@ mod tests {
                 Tree(1),
                 Tree(9),
                 Tree(1),
-            ],
+            ]),
         };
 
         assert_eq!(
             forest.look_up(1, 1).collect::<Vec<_>>(),
-            vec![&forest.trees[1]],
+            vec![&forest.trees[(1, 0)]],
         )
     }
 
     #[test]
     fn test_look_down() {
         let forest = Forest {
-            width: 3,
-            height: 3,
-            trees: vec![
+            trees: Grid::from_vec(3, vec![
                 Tree(1),
                 Tree(2),
                 Tree(3),
@@ -194,21 +165,19 @@ mod tests {
                 Tree(1),
                 Tree(9),
                 Tree(1),
-            ],
+            ]),
         };
 
         assert_eq!(
             forest.look_down(1, 1).collect::<Vec<_>>(),
-            vec![&forest.trees[7]],
+            vec![&forest.trees[(1, 2)]],
         )
     }
 
     #[test]
     fn test_look_left() {
         let forest = Forest {
-            width: 3,
-            height: 3,
-            trees: vec![
+            trees: Grid::from_vec(3, vec![
                 Tree(1),
                 Tree(2),
                 Tree(3),
@@ -218,21 +187,19 @@ mod tests {
                 Tree(1),
                 Tree(9),
                 Tree(1),
-            ],
+            ]),
         };
 
         assert_eq!(
             forest.look_left(1, 1).collect::<Vec<_>>(),
-            vec![&forest.trees[3]],
+            vec![&forest.trees[(0, 1)]],
         )
     }
 
     #[test]
     fn test_look_right() {
         let forest = Forest {
-            width: 3,
-            height: 3,
-            trees: vec![
+            trees: Grid::from_vec(3, vec![
                 Tree(1),
                 Tree(2),
                 Tree(3),
@@ -242,12 +209,12 @@ mod tests {
                 Tree(5),
                 Tree(9),
                 Tree(6),
-            ],
+            ]),
         };
 
         assert_eq!(
             forest.look_right(1, 1).collect::<Vec<_>>(),
-            vec![&forest.trees[5]],
+            vec![&forest.trees[(2, 1)]],
         )
     }
 }
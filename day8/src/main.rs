@@ -1,13 +1,28 @@
-use std::fs;
+use std::process;
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day8::*;
 
 fn main() {
-    let contents = fs::read_to_string("day8/input.txt").unwrap();
-
-    println!("Part 1: {}", solve_part_1(&contents));
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).unwrap();
+    let input = Day8::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
+
+    match Day8::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    match Day8::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 }
 
 #[cfg(test)]
@@ -22,15 +37,25 @@ mod tests {
 
     #[test]
     fn test_part_1() {
-        let sol = solve_part_1(INPUT);
+        let sol = solve_part_1(&parse_input(INPUT).unwrap());
 
         assert_eq!(sol, 21);
     }
 
     #[test]
     fn test_part_2() {
-        let sol = solve_part_2(INPUT);
+        let sol = solve_part_2(&parse_input(INPUT).unwrap());
 
         assert_eq!(sol, 8);
     }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let forest = parse_input(&aoc_generate::generate::<Day8>(seed, 10)).unwrap();
+
+            assert!((36..=100).contains(&solve_part_1(&forest)));
+            solve_part_2(&forest);
+        }
+    }
 }
@@ -0,0 +1,18 @@
+use aoc_generate::{
+    rand::{seq::IndexedRandom, RngExt},
+    Generated, InputRng,
+};
+
+use crate::Day9;
+
+impl Generated for Day9 {
+    /// `size` moves of the head of the rope.
+    fn generate(rng: &mut InputRng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let direction = ['U', 'D', 'L', 'R'].choose(rng).unwrap();
+                format!("{direction} {}\n", rng.random_range(1..=19))
+            })
+            .collect()
+    }
+}
@@ -1,80 +1,89 @@
-use std::{ops::{Sub, AddAssign}, str::FromStr, collections::HashSet};
+use std::collections::HashSet;
 
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_geometry::{BoundingBox, Direction, Point2};
+use aoc_visual::{Animated, Frames, NoFrames};
+
+mod generator;
 mod part1;
 mod part2;
 
 pub use part1::solve_part_1;
-pub use part2::solve_part_2;
-
-#[derive(Clone, Copy)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+pub use part2::{animate_part_2, solve_part_2};
 
-struct Move {
-    direction: Direction,
-    count: u8,
-}
+pub struct Day9;
 
-impl FromStr for Direction {
-    type Err = &'static str;
+impl Solution for Day9 {
+    type Input<'a> = Vec<Move>;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let dir = match s {
-            "U" => Direction::Up,
-            "D" => Direction::Down,
-            "L" => Direction::Left,
-            "R" => Direction::Right,
-            _ => return Err("Invalid direction"),
-        };
-
-        Ok(dir)
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
     }
-}
-
-impl FromStr for Move {
-    type Err = &'static str;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split(' ');
-        let dir_str = split.next().ok_or("No direction")?;
-        let count_str = split.next().ok_or("No count")?;
 
-        let direction = dir_str.parse()?;
-        let count = count_str.parse().map_err(|_| "Invalid count")?;
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input).into())
+    }
 
-        Ok(Move { direction, count })
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input).into())
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-struct Point(i32, i32);
-
-impl Point {
-    fn mov(&mut self, dir: Direction) {
-        match dir {
-            Direction::Up => self.1 += 1,
-            Direction::Down => self.1 -= 1,
-            Direction::Left => self.0 -= 1,
-            Direction::Right => self.0 += 1,
-        }
+impl Animated for Day9 {
+    fn animate(
+        input: &Self::Input<'_>,
+        part: u8,
+        frames: &mut impl Frames,
+    ) -> Result<Answer, SolveError> {
+        Ok(match part {
+            1 => solve_part_1(input).into(),
+            2 => animate_part_2(input, frames).into(),
+            _ => return Err(SolveError::new(format!("No part {part} to animate"))),
+        })
     }
 }
 
-impl AddAssign for Point {
-    fn add_assign(&mut self, rhs: Self) {
-        self.0 += rhs.0;
-        self.1 += rhs.1;
-    }
+pub fn parse_input(input: &str) -> Result<Vec<Move>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (dir_str, count_str) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(input, line, "direction and count separated by a space"))?;
+
+            let direction = parse_direction(dir_str)
+                .ok_or_else(|| ParseError::new(input, dir_str, "direction (U, D, L or R)"))?;
+            let count = count_str
+                .parse()
+                .map_err(|_| ParseError::new(input, count_str, "move count"))?;
+
+            Ok(Move { direction, count })
+        })
+        .collect()
+}
+
+pub struct Move {
+    direction: Direction,
+    count: u8,
+}
+
+fn parse_direction(s: &str) -> Option<Direction> {
+    let dir = match s {
+        "U" => Direction::Up,
+        "D" => Direction::Down,
+        "L" => Direction::Left,
+        "R" => Direction::Right,
+        _ => return None,
+    };
+
+    Some(dir)
 }
 
-impl Sub for Point {
-    type Output = Self;
+type Point = Point2<i32>;
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self(self.0 - rhs.0, self.1 - rhs.1)
+/// Moves `knot` one step towards `leader`, unless they are already touching.
+fn follow(knot: &mut Point, leader: Point) {
+    if knot.chebyshev(leader) >= 2 {
+        *knot += (leader - *knot).signum();
     }
 }
@@ -1,17 +1,34 @@
-use std::fs;
+use std::process;
 
+use aoc_core::{init_logging, read_input_from_args, Solution};
 use day9::*;
 
 fn main() {
-    let contents = fs::read_to_string("day9/input.txt").expect("Failed to read puzzle input");
+    init_logging(None).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+    let contents = read_input_from_args(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt")).expect("Failed to read puzzle input");
+    let input = Day9::parse(&contents).unwrap_or_else(|err| {
+        eprintln!("Failed to parse puzzle input: {err}");
+        process::exit(1);
+    });
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day9::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 1: {err}"),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    match Day9::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Failed to solve part 2: {err}"),
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_visual::{Animated, Animation, NoFrames};
+
     use super::*;
 
     const INPUT: &str = "R 4
@@ -25,29 +42,55 @@ R 2";
 
     #[test]
     fn test_part_1() {
-        let sol = solve_part_1(INPUT);
+        let sol = solve_part_1(&parse_input(INPUT).unwrap());
 
         assert_eq!(sol, 13);
     }
 
     #[test]
     fn test_part_2() {
-        let sol = solve_part_2(INPUT);
+        let sol = solve_part_2(&parse_input(INPUT).unwrap());
 
         assert_eq!(sol, 1);
     }
 
     #[test]
     fn test_part_2_larger() {
-        let sol = solve_part_2("R 5
+        let sol = solve_part_2(&parse_input("R 5
 U 8
 L 8
 D 3
 R 17
 D 10
 L 25
-U 20");
+U 20").unwrap());
 
         assert_eq!(sol, 36);
     }
+
+    #[test]
+    fn test_animate() {
+        let mut animation = Animation::headless(Vec::new());
+        let sol = animate_part_2(&parse_input(INPUT).unwrap(), &mut animation);
+
+        assert_eq!(sol, 1);
+        assert_eq!(animation.offered(), 24);
+
+        let frames = String::from_utf8(animation.finish().unwrap()).unwrap();
+        let first_frame = frames.split("Frame 1\n").next().unwrap();
+        assert_eq!(first_frame.lines().count(), 1 + 11 + 1);
+        assert!(first_frame.contains("\n.....1H.....\n"));
+
+        assert!(Day9::animate(&parse_input(INPUT).unwrap(), 3, &mut NoFrames).is_err());
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let moves = parse_input(&aoc_generate::generate::<Day9>(seed, 50)).unwrap();
+
+            assert_eq!(moves.len(), 50);
+            assert!(solve_part_1(&moves) >= solve_part_2(&moves));
+        }
+    }
 }
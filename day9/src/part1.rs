@@ -1,40 +1,13 @@
 use super::*;
 
-pub fn solve_part_1(input: &str) -> usize {
-    let moves: Vec<Move> = input
-        .lines()
-        .map(|line| line.parse().expect("Failed to parse input"))
-        .collect();
-
+pub fn solve_part_1(moves: &[Move]) -> usize {
     let mut bridge = Bridge::new();
     let mut visited = HashSet::new();
     visited.insert(bridge.tail);
     for mov in moves {
         for _ in 0..mov.count {
-            bridge.head.mov(mov.direction);
-
-            let Point(x_offset, y_offset) = bridge.head - bridge.tail;
-            if x_offset.abs() >= 2 {
-                bridge.tail.mov(
-                    if x_offset > 0 { Direction::Right } else { Direction::Left }
-                );
-
-                if y_offset.abs() >= 1 {
-                    bridge.tail.mov(
-                        if y_offset > 0 { Direction::Up } else { Direction::Down }
-                    );
-                }
-            } else if y_offset.abs() >= 2 {
-                bridge.tail.mov(
-                    if y_offset > 0 { Direction::Up } else { Direction::Down }
-                );
-
-                if x_offset.abs() >= 1 {
-                    bridge.tail.mov(
-                        if x_offset > 0 { Direction::Right } else { Direction::Left }
-                    );
-                }
-            }
+            bridge.head += mov.direction.delta();
+            follow(&mut bridge.tail, bridge.head);
 
             visited.insert(bridge.tail);
         }
@@ -57,8 +30,8 @@ impl Bridge {
 impl Default for Bridge {
     fn default() -> Self {
         Self {
-            head: Point(0, 0),
-            tail: Point(0, 0),
+            head: Point::ORIGIN,
+            tail: Point::ORIGIN,
         }
     }
 }
@@ -1,11 +1,11 @@
 use super::*;
 
-pub fn solve_part_2(input: &str) -> usize {
-    let moves: Vec<Move> = input
-        .lines()
-        .map(|line| line.parse().expect("Failed to parse input"))
-        .collect();
+pub fn solve_part_2(moves: &[Move]) -> usize {
+    animate_part_2(moves, &mut NoFrames)
+}
 
+/// Solves part 2, showing the rope and the positions its tail visited after every step.
+pub fn animate_part_2(moves: &[Move], frames: &mut impl Frames) -> usize {
     let mut bridge = Bridge::new(10);
     let mut visited: HashSet<Point> = HashSet::new();
     visited.insert(*bridge.tail_mut());
@@ -13,6 +13,7 @@ pub fn solve_part_2(input: &str) -> usize {
         for _ in 0..mov.count {
             bridge.move_head(mov.direction);
             visited.insert(*bridge.tail_mut());
+            frames.frame(|| bridge.render(&visited));
         }
     }
 
@@ -27,7 +28,7 @@ struct Bridge {
 impl Bridge {
     fn new(len: usize) -> Self {
         Self {
-            knots: vec![Point(0, 0); len],
+            knots: vec![Point::ORIGIN; len],
         }
     }
 
@@ -39,38 +40,36 @@ impl Bridge {
         self.knots.last_mut().expect("Bridge has no tail")
     }
 
-    /// Moves head in specified direction, then rest of the knots according to the rules.
-    fn move_head(&mut self, dir: Direction) {
-        self.head_mut().mov(dir);
+    /// Draws the knots, head first, on top of `visited` positions around the rope, with `y` up.
+    fn render(&self, visited: &HashSet<Point>) -> String {
+        let bounds = BoundingBox::from_points(self.knots.iter().copied())
+            .expect("Bridge has no knots")
+            .expand(Point::new(5, 5));
 
-        let indices_vec = Vec::from_iter(0..self.knots.len());
-        for indices in indices_vec.windows(2) {
-            let prev_knot = self.knots[indices[0]];
-            let curr_knot = self.knots[indices[1]];
-            let Point(x_offset, y_offset) = prev_knot - curr_knot;
+        let mut out = String::new();
+        for y in (bounds.min.y..=bounds.max.y).rev() {
+            for x in bounds.min.x..=bounds.max.x {
+                let point = Point::new(x, y);
+                out.push(match self.knots.iter().position(|&knot| knot == point) {
+                    Some(0) => 'H',
+                    Some(i) => char::from_digit(i as u32, 36).unwrap_or('T'),
+                    None if visited.contains(&point) => '#',
+                    None => '.',
+                });
+            }
+            out.push('\n');
+        }
 
-            let curr_knot = &mut self.knots[indices[1]];
-            if x_offset.abs() >= 2 {
-                curr_knot.mov(
-                    if x_offset > 0 { Direction::Right } else { Direction::Left }
-                );
+        out
+    }
 
-                if y_offset.abs() >= 1 {
-                    curr_knot.mov(
-                        if y_offset > 0 { Direction::Up } else { Direction::Down }
-                    );
-                }
-            } else if y_offset.abs() >= 2 {
-                curr_knot.mov(
-                    if y_offset > 0 { Direction::Up } else { Direction::Down }
-                );
+    /// Moves head in specified direction, then rest of the knots according to the rules.
+    fn move_head(&mut self, dir: Direction) {
+        *self.head_mut() += dir.delta();
 
-                if x_offset.abs() >= 1 {
-                    curr_knot.mov(
-                        if x_offset > 0 { Direction::Right } else { Direction::Left }
-                    );
-                }
-            }
+        for i in 1..self.knots.len() {
+            let leader = self.knots[i - 1];
+            follow(&mut self.knots[i], leader);
         }
     }
 }
@@ -0,0 +1,5 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| aoc_fuzz::parse_and_solve::<day11::Day11>(input));
@@ -0,0 +1,9 @@
+use aoc_core::Solution;
+
+/// Parses `input` and, if that succeeds, solves both parts, neither of which may panic.
+pub fn parse_and_solve<S: Solution>(input: &str) {
+    if let Ok(input) = S::parse(input) {
+        let _ = S::part_1(&input);
+        let _ = S::part_2(&input);
+    }
+}
@@ -0,0 +1,10 @@
+use aoc_generate::{Generated, InputRng};
+
+use crate::{{crate_name | pascal_case}};
+
+impl Generated for {{crate_name | pascal_case}} {
+    /// `size` lines of the puzzle input.
+    fn generate(_rng: &mut InputRng, _size: usize) -> String {
+        todo!()
+    }
+}
@@ -1,11 +1,30 @@
-mod parser;
+mod generator;
+pub mod parser;
 
-use parser::parse_input;
+use aoc_core::{Answer, ParseError, Solution, SolveError};
 
-pub fn solve_part_1(input: &str) -> usize {
+pub struct {{crate_name | pascal_case}};
+
+impl Solution for {{crate_name | pascal_case}} {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parser::parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_1(input).into())
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(solve_part_2(input).into())
+    }
+}
+
+pub fn solve_part_1(_input: &[&str]) -> usize {
     todo!()
 }
 
-pub fn solve_part_2(input: &str) -> usize {
+pub fn solve_part_2(_input: &[&str]) -> usize {
     todo!()
 }
@@ -1,10 +1,18 @@
-use nom::IResult;
+use aoc_core::ParseError;
 
-use crate::*;
-
-pub(crate) fn parse_input(input: &str) {}
+pub fn parse_input(_input: &str) -> Result<Vec<&str>, ParseError> {
+    todo!()
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    #[test]
+    #[ignore]
+    fn test_parse_input() {
+        let input = parse_input(include_str!("../example.txt")).unwrap();
+
+        assert!(!input.is_empty());
+    }
 }